@@ -0,0 +1,228 @@
+use std::io::{self, BufRead};
+use std::process;
+use chrono::{Local, NaiveDate};
+use clap::{Parser, Subcommand};
+use rayon::prelude::*;
+use serde_json::{json, Value};
+use ywd_core::{
+    answers, is_valid_guess, parse_word, puzzle_number, solve, turns_to_solve, word_for_puzzle,
+    Evaluation, FirstCandidate, LetterHint, MaxPartitions, Row, Solver, Strategy, Wordle, SOLVE_LIMIT,
+};
+
+#[derive(Parser)]
+#[clap(name = "ywd", about = "Wordle tools for scripting")]
+struct Cli {
+    /// Print results as JSON
+    #[clap(long, global = true)]
+    json: bool,
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the hints for a guess against an answer
+    Score {
+        answer: String,
+        guess: String,
+    },
+    /// Read `<guess> <hints>` lines from stdin and print the remaining answers
+    Filter,
+    /// Show the path a solver takes to an answer
+    Solve {
+        answer: String,
+        /// `partition` or `first`
+        #[clap(long, default_value = "partition")]
+        strategy: String,
+        /// First guess for the partition strategy
+        #[clap(long)]
+        opener: Option<String>,
+    },
+    /// Run a solver against every answer and report how it does
+    Bench {
+        /// `partition` or `first`
+        #[clap(long, default_value = "partition")]
+        strategy: String,
+        /// First guess for the partition strategy
+        #[clap(long)]
+        opener: Option<String>,
+        /// Number of worst cases to list
+        #[clap(long, default_value = "10")]
+        worst: usize,
+    },
+    /// Print the puzzle for a date (defaults to today)
+    Daily {
+        #[clap(long)]
+        date: Option<NaiveDate>,
+    },
+}
+
+fn parse_guess(word: &str) -> Result<[char; 5], String> {
+    parse_word(word)
+        .filter(|_| {is_valid_guess(word.to_ascii_lowercase().as_str())})
+        .ok_or_else(|| {format!("`{}` is not in the word list", word)})
+}
+
+/// Reads a word that has to be one of the answers, e.g. what `solve` is asked to find.
+fn parse_answer(word: &str) -> Result<String, String> {
+    let answer = parse_word(word).map(|chars| {chars.iter().collect::<String>()})
+        .ok_or_else(|| {format!("`{}` is not a 5-letter word", word)})?;
+    match answers().binary_search(&answer.as_str()) {
+        Ok(_) => Ok(answer),
+        Err(_) => Err(format!("`{}` is not one of the answers", word)),
+    }
+}
+
+fn parse_hints(pattern: &str) -> Result<[LetterHint; 5], String> {
+    pattern.chars()
+        .map(LetterHint::from_symbol)
+        .collect::<Option<Vec<LetterHint>>>()
+        .and_then(|hints| {hints.try_into().ok()})
+        .ok_or_else(|| {format!("`{}` is not a hint pattern, use 5 of `g`, `y` and `.`", pattern)})
+}
+
+fn hints_json(hints: &[(char, LetterHint)]) -> Value {
+    json!(Row(hints.to_vec()))
+}
+
+fn hints_text(hints: &[(char, LetterHint)]) -> String {
+    format!(
+        "{} {}",
+        hints.iter().map(|(c, _)| {c.to_ascii_uppercase()}).collect::<String>(),
+        hints.iter().map(|(_, h)| {h.emoji()}).collect::<String>(),
+    )
+}
+
+fn strategy(name: &str, opener: Option<&str>) -> Result<Box<dyn Strategy>, String> {
+    let opener = opener.map(parse_guess).transpose()?;
+    match name {
+        "partition" => Ok(Box::new(MaxPartitions::new(opener))),
+        "first" => Ok(Box::new(FirstCandidate)),
+        _ => Err(format!("unknown strategy `{}`", name)),
+    }
+}
+
+fn score(answer: &str, guess: &str, as_json: bool) -> Result<(), String> {
+    let answer = parse_word(answer).ok_or_else(|| {format!("`{}` is not a 5-letter word", answer)})?;
+    let hints = Wordle::new(answer).guess(parse_guess(guess)?)
+        .map_err(|_| {format!("`{}` is not in the word list", guess)})?;
+    if as_json {
+        println!("{}", hints_json(&hints));
+    } else {
+        println!("{}", hints_text(&hints));
+    }
+    Ok(())
+}
+
+fn filter(as_json: bool) -> Result<(), String> {
+    let mut solver = Solver::new();
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|e| {e.to_string()})?;
+        match line.split_whitespace().collect::<Vec<&str>>().as_slice() {
+            [] => continue,
+            [guess, pattern] => solver.apply(parse_guess(guess)?, parse_hints(pattern)?),
+            _ => return Err(format!("expected `<guess> <hints>`, got `{}`", line)),
+        }
+    }
+    if as_json {
+        println!("{}", json!(solver.candidates()));
+    } else {
+        solver.candidates().iter().for_each(|word| {println!("{}", word)});
+    }
+    Ok(())
+}
+
+fn solve_answer(answer: &str, strategy_name: &str, opener: Option<&str>, as_json: bool) -> Result<(), String> {
+    let answer = parse_answer(answer)?;
+    let strategy = strategy(strategy_name, opener)?;
+    let path = solve(&answer, strategy.as_ref(), SOLVE_LIMIT);
+    if as_json {
+        println!("{}", json!({
+            "answer": answer,
+            "strategy": strategy.name(),
+            "guesses": path.iter().map(|hints| {hints_json(hints)}).collect::<Vec<Value>>(),
+        }));
+    } else {
+        path.iter().for_each(|hints| {println!("{}", hints_text(hints))});
+    }
+    Ok(())
+}
+
+fn bench(strategy_name: &str, opener: Option<&str>, worst: usize, as_json: bool) -> Result<(), String> {
+    let strategy = strategy(strategy_name, opener)?;
+    let results = answers().par_iter()
+        .map(|answer| {(*answer, turns_to_solve(answer, strategy.as_ref()))})
+        .collect::<Vec<(&'static str, Option<usize>)>>();
+    let evaluation = Evaluation::from_results(results, worst);
+    if as_json {
+        println!("{}", json!({
+            "strategy": strategy.name(),
+            "games": evaluation.games,
+            "average": evaluation.average(),
+            "distribution": evaluation.distribution,
+            "failures": evaluation.failures,
+            "worst": evaluation.worst.iter()
+                .map(|(answer, turns)| {json!({"answer": answer, "guesses": turns})})
+                .collect::<Vec<Value>>(),
+        }));
+    } else {
+        println!("strategy: {}", strategy.name());
+        println!("games:    {}", evaluation.games);
+        println!("average:  {:.4}", evaluation.average());
+        let max = evaluation.distribution.iter().copied().max().unwrap_or(0).max(1);
+        evaluation.distribution.iter().enumerate().for_each(|(i, count)| {
+            println!("{:>2} {:>5} {}", i + 1, count, "#".repeat(count * 50 / max));
+        });
+        println!("failures: {}", evaluation.failures.join(" "));
+        println!("worst:    {}", evaluation.worst.iter()
+            .map(|(answer, turns)| {format!("{}({})", answer, turns)})
+            .collect::<Vec<String>>()
+            .join(" "));
+    }
+    Ok(())
+}
+
+fn daily(date: Option<NaiveDate>, as_json: bool) -> Result<(), String> {
+    let date = date.unwrap_or_else(|| {Local::now().naive_local().date()});
+    let number = puzzle_number(date);
+    let answer = word_for_puzzle(number);
+    if as_json {
+        println!("{}", json!({
+            "date": date.to_string(),
+            "puzzle": number,
+            "answer": answer,
+        }));
+    } else {
+        println!("{} #{} {}", date, number, answer);
+    }
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match &cli.command {
+        Command::Score { answer, guess } => score(answer, guess, cli.json),
+        Command::Filter => filter(cli.json),
+        Command::Solve { answer, strategy, opener } =>
+            solve_answer(answer, strategy, opener.as_deref(), cli.json),
+        Command::Bench { strategy, opener, worst } =>
+            bench(strategy, opener.as_deref(), *worst, cli.json),
+        Command::Daily { date } => daily(*date, cli.json),
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answer() {
+        assert_eq!(Ok("cigar".to_string()), parse_answer("CIGAR"));
+        assert!(parse_answer("zzzzz").is_err());
+        assert!(parse_answer("cigars").is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::history::GameRecord;
+use crate::session::{GameStatus, MAX_GUESSES};
+use crate::stats::Stats;
+
+/// The layout of exported player data. Bump it when the layout changes, and keep reading the older ones.
+pub const BACKUP_VERSION: u64 = 1;
+
+/// Everything a player keeps on one device.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Backup {
+    pub stats: Stats,
+    pub history: Vec<GameRecord>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum BackupError {
+    /// Not laid out like an export at all.
+    Malformed,
+    UnsupportedVersion(u64),
+    InvalidStats,
+    /// The position of the first history entry that doesn't parse.
+    InvalidRecord(usize),
+}
+
+pub fn check_backup_version(version: u64) -> Result<(), BackupError> {
+    match version {
+        1..=BACKUP_VERSION => Ok(()),
+        _ => Err(BackupError::UnsupportedVersion(version)),
+    }
+}
+
+impl Backup {
+    /// Reads the fields of an export, in the same text formats the game stores them in.
+    pub fn parse(stats: &str, history: &[&str]) -> Result<Self, BackupError> {
+        Ok(Self {
+            stats: stats.parse().map_err(|_| BackupError::InvalidStats)?,
+            history: history.iter()
+                .enumerate()
+                .map(|(i, line)| {line.parse().map_err(|_| BackupError::InvalidRecord(i))})
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Combines the data of two devices. Histories are joined, dropping games both already have, and
+    /// the stats are counted again from the joined log. Games counted before the log existed can't be
+    /// told apart, so the side with more of them wins rather than counting an import of our own export
+    /// twice. Neither side's best streak is lost.
+    pub fn merge(&self, other: &Backup) -> Backup {
+        let mut history = self.history.clone();
+        history.extend(other.history.iter().filter(|record| {!self.history.contains(record)}).cloned());
+        history.sort_by_key(|record| {record.finished});
+
+        let replayed = daily_stats(&history);
+        let (own, theirs) = (unlogged(&self.stats, &self.history), unlogged(&other.stats, &other.history));
+        let mut distribution = [0; MAX_GUESSES];
+        for (i, count) in distribution.iter_mut().enumerate() {
+            *count = replayed.distribution[i] + own.distribution[i].max(theirs.distribution[i]);
+        }
+        let current_streak = if replayed.played > 0 {
+            replayed.current_streak
+        } else {
+            self.stats.current_streak.max(other.stats.current_streak)
+        };
+        let stats = Stats {
+            played: replayed.played + own.played.max(theirs.played),
+            wins: replayed.wins + own.wins.max(theirs.wins),
+            current_streak,
+            max_streak: replayed.max_streak.max(self.stats.max_streak).max(other.stats.max_streak),
+            distribution,
+            hinted: replayed.hinted + own.hinted.max(theirs.hinted),
+        };
+        Backup { stats, history }
+    }
+}
+
+/// Stats only ever count daily games, so those are the ones replayed from the log.
+fn daily_stats(history: &[GameRecord]) -> Stats {
+    let mut stats = Stats::default();
+    for record in history.iter().filter(|record| {record.mode == "daily"}) {
+        let status = if record.is_won() { GameStatus::Won } else { GameStatus::Lost };
+        stats.record(status, record.turns(), record.hints);
+    }
+    stats
+}
+
+/// The part of `stats` that the log doesn't account for, i.e. games played before it was kept.
+fn unlogged(stats: &Stats, history: &[GameRecord]) -> Stats {
+    let logged = daily_stats(history);
+    let mut distribution = [0; MAX_GUESSES];
+    for (i, count) in distribution.iter_mut().enumerate() {
+        *count = stats.distribution[i].saturating_sub(logged.distribution[i]);
+    }
+    Stats {
+        played: stats.played.saturating_sub(logged.played),
+        wins: stats.wins.saturating_sub(logged.wins),
+        distribution,
+        hinted: stats.hinted.saturating_sub(logged.hinted),
+        ..Stats::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(finished: u64, rows: &str) -> GameRecord {
+        let guesses = rows.split('-').map(|_| {"crane"}).collect::<Vec<_>>().join(",");
+        format!("{} {} daily {} 0 {} {}", finished - 1000, finished, finished, guesses, rows).parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(()), check_backup_version(1));
+        assert_eq!(Err(BackupError::UnsupportedVersion(2)), check_backup_version(2));
+        assert_eq!(Err(BackupError::InvalidStats), Backup::parse("1 1", &[]));
+        let line = record(5000, "ggggg").to_string();
+        assert_eq!(Err(BackupError::InvalidRecord(1)), Backup::parse("0 0 0 0 0 0 0 0 0 0", &[&line, "oops"]));
+        assert_eq!(1, Backup::parse("1 1 1 1 1 0 0 0 0 0", &[&line]).unwrap().history.len());
+    }
+
+    #[test]
+    fn test_merge_joins_histories_and_recounts_streaks() {
+        let (won, lost) = ("y....-ggggg", ["y....", "y....", "y....", "y....", "y....", "y...."].join("-"));
+        let mut phone = Backup { history: vec![record(1000, won), record(3000, &lost)], ..Backup::default() };
+        phone.history.iter().for_each(|r| {phone.stats.record(if r.is_won() { GameStatus::Won } else { GameStatus::Lost }, r.turns(), 0)});
+        // The laptop counted a game before the log was kept, and already has the phone's first game from an earlier import.
+        let mut laptop = Backup { history: vec![record(1000, won), record(4000, won)], ..Backup::default() };
+        (0..3).for_each(|_| {laptop.stats.record(GameStatus::Won, 2, 0)});
+
+        let merged = phone.merge(&laptop);
+        assert_eq!(3, merged.history.len());
+        assert_eq!(vec![1000, 3000, 4000], merged.history.iter().map(|r| {r.finished}).collect::<Vec<_>>());
+        assert_eq!(4, merged.stats.played);
+        assert_eq!(3, merged.stats.wins);
+        assert_eq!([0, 3, 0, 0, 0, 0], merged.stats.distribution);
+        assert_eq!(1, merged.stats.current_streak);
+        assert_eq!(3, merged.stats.max_streak);
+        assert_eq!(merged, merged.merge(&merged));
+    }
+}
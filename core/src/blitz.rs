@@ -0,0 +1,104 @@
+use crate::session::{GameSession, GameStatus};
+use crate::timer::format_duration;
+use crate::wordle::Wordle;
+
+pub const BLITZ_MILLIS: u64 = 3 * 60 * 1000;
+
+/// Chains consecutive puzzles for as long as the time window lasts.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Blitz {
+    first_puzzle: i64,
+    limit: u64,
+    played: usize,
+    solved: usize,
+}
+
+impl Blitz {
+    pub fn new(first_puzzle: i64, limit: u64) -> Self {
+        Self {
+            first_puzzle,
+            limit,
+            played: 0,
+            solved: 0,
+        }
+    }
+
+    pub fn played(&self) -> usize {
+        self.played
+    }
+
+    pub fn solved(&self) -> usize {
+        self.solved
+    }
+
+    /// The number of the puzzle being played.
+    pub fn puzzle(&self) -> i64 {
+        self.first_puzzle + self.played as i64
+    }
+
+    pub fn session(&self) -> GameSession {
+        GameSession::new(Wordle::new_for_puzzle(self.puzzle()))
+    }
+
+    /// Counts a finished puzzle; the next call to `session` moves on to the following one.
+    pub fn record(&mut self, status: GameStatus) {
+        match status {
+            GameStatus::InProgress => return,
+            GameStatus::Won => self.solved += 1,
+            GameStatus::Lost => (),
+        }
+        self.played += 1;
+    }
+
+    pub fn remaining(&self, elapsed: u64) -> u64 {
+        self.limit.saturating_sub(elapsed)
+    }
+
+    pub fn is_over(&self, elapsed: u64) -> bool {
+        self.remaining(elapsed) == 0
+    }
+
+    pub fn share_text(&self) -> String {
+        format!("YDW Blitz {}/{} ⏱ {}", self.solved, self.played, format_duration(self.limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::GuessError;
+    use crate::timer::{ManualClock, Stopwatch};
+    use crate::wordle::{word_for_puzzle, LetterHint};
+    use crate::wordle::LetterHint::Correct;
+
+    fn word(number: i64) -> Vec<char> {
+        word_for_puzzle(number).chars().collect()
+    }
+
+    fn hints(row: Result<Vec<(char, LetterHint)>, GuessError>) -> Result<Vec<LetterHint>, GuessError> {
+        row.map(|row| {row.into_iter().map(|(_, hint)| {hint}).collect()})
+    }
+
+    #[test]
+    fn test_blitz_chains_puzzles_until_time_runs_out() {
+        let clock = ManualClock::default();
+        let mut stopwatch = Stopwatch::new(clock.clone());
+        let mut blitz = Blitz::new(0, 60_000);
+        stopwatch.start();
+
+        let mut session = blitz.session();
+        assert_eq!(None, session.answer());
+        session.guess(&word(0)).unwrap();
+        assert_eq!(Some(word_for_puzzle(0)), session.answer());
+        blitz.record(session.status());
+        assert_eq!(Ok(vec![Correct; 5]), hints(blitz.session().guess(&word(1))));
+
+        clock.advance(45_000);
+        assert_eq!(15_000, blitz.remaining(stopwatch.elapsed()));
+        blitz.record(GameStatus::Lost);
+        clock.advance(15_000);
+        assert!(blitz.is_over(stopwatch.elapsed()));
+        assert_eq!(Ok(vec![Correct; 5]), hints(blitz.session().guess(&word(2))));
+        assert_eq!("YDW Blitz 1/2 ⏱ 1:00.0", blitz.share_text());
+    }
+}
@@ -0,0 +1,184 @@
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum PasteError {
+    WrongLength,
+    InvalidCharacter(char),
+}
+
+/// Turns pasted text into a guess: whitespace is dropped and letters are lowercased, anything
+/// else the puzzle doesn't accept is rejected.
+pub fn sanitize_paste(text: &str, width: usize, accepts: impl Fn(char) -> bool) -> Result<Vec<char>, PasteError> {
+    let chars = text.chars()
+        .filter(|c| {!c.is_whitespace()})
+        .map(|c| {c.to_ascii_lowercase()})
+        .collect::<Vec<_>>();
+    if let Some(&c) = chars.iter().find(|&&c| {!accepts(c)}) {
+        return Err(PasteError::InvalidCharacter(c));
+    }
+    if chars.len() != width {
+        return Err(PasteError::WrongLength);
+    }
+    Ok(chars)
+}
+
+/// The guess being typed: a fixed number of slots and a cursor that can be moved back to
+/// overwrite any of them. The cursor sits one past the last slot once the row is filled in order.
+#[derive(Clone, PartialEq, Debug)]
+pub struct GuessBuffer {
+    slots: Vec<Option<char>>,
+    cursor: usize,
+}
+
+impl GuessBuffer {
+    pub fn new(width: usize) -> Self {
+        Self {
+            slots: vec![None; width],
+            cursor: 0,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn get(&self, i: usize) -> Option<char> {
+        self.slots.get(i).copied().flatten()
+    }
+
+    /// Number of filled slots.
+    pub fn len(&self) -> usize {
+        self.slots.iter().flatten().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.slots.iter().all(Option::is_some)
+    }
+
+    pub fn to_vec(&self) -> Vec<char> {
+        self.slots.iter().flatten().copied().collect()
+    }
+
+    /// Writes at the cursor, overwriting whatever was there, and moves on to the next slot.
+    pub fn insert(&mut self, c: char) -> bool {
+        match self.slots.get_mut(self.cursor) {
+            Some(slot) => {
+                *slot = Some(c);
+                self.cursor += 1;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Clears the slot under the cursor if it is filled, otherwise the one before it.
+    pub fn delete(&mut self) -> bool {
+        if self.get(self.cursor).is_none() {
+            if self.cursor == 0 {
+                return false;
+            }
+            self.cursor -= 1;
+        }
+        self.slots[self.cursor].take().is_some()
+    }
+
+    pub fn move_to(&mut self, i: usize) -> bool {
+        if i >= self.width() || i == self.cursor {
+            return false;
+        }
+        self.cursor = i;
+        true
+    }
+
+    pub fn move_left(&mut self) -> bool {
+        self.cursor > 0 && self.move_to(self.cursor.min(self.width()) - 1)
+    }
+
+    pub fn move_right(&mut self) -> bool {
+        self.move_to(self.cursor + 1)
+    }
+
+    /// Replaces the whole row, leaving the cursor at the end.
+    pub fn fill(&mut self, chars: &[char]) -> bool {
+        if chars.len() != self.width() {
+            return false;
+        }
+        self.slots = chars.iter().map(|&c| {Some(c)}).collect();
+        self.cursor = self.width();
+        true
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new(self.width());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filled(word: &str) -> GuessBuffer {
+        let mut buffer = GuessBuffer::new(word.len());
+        word.chars().for_each(|c| {assert!(buffer.insert(c))});
+        buffer
+    }
+
+    #[test]
+    fn test_typing_and_deleting_from_the_end() {
+        let mut buffer = filled("crane");
+        assert!(buffer.is_full());
+        assert!(!buffer.insert('x'));
+        assert!(buffer.delete());
+        assert_eq!(vec!['c', 'r', 'a', 'n'], buffer.to_vec());
+        assert_eq!(4, buffer.cursor());
+    }
+
+    #[test]
+    fn test_overwriting_in_the_middle() {
+        let mut buffer = filled("crane");
+        assert!(buffer.move_to(1));
+        assert!(buffer.insert('h'));
+        assert_eq!(2, buffer.cursor());
+        assert!(buffer.move_left());
+        assert!(buffer.delete());
+        assert_eq!(Some('c'), buffer.get(0));
+        assert_eq!(None, buffer.get(1));
+        assert!(!buffer.is_full());
+        assert_eq!(4, buffer.len());
+        assert!(buffer.delete());
+        assert_eq!(0, buffer.cursor());
+        assert!(!buffer.move_to(5));
+    }
+
+    #[test]
+    fn test_sanitize_paste() {
+        let is_letter = |c: char| {c.is_ascii_lowercase()};
+        assert_eq!(Ok(vec!['c', 'r', 'a', 'n', 'e']), sanitize_paste(" CRANE\n", 5, is_letter));
+        assert_eq!(Ok(vec!['c', 'r', 'a', 'n', 'e']), sanitize_paste("c r a n e", 5, is_letter));
+        assert_eq!(Err(PasteError::WrongLength), sanitize_paste("cranes", 5, is_letter));
+        assert_eq!(Err(PasteError::InvalidCharacter('!')), sanitize_paste("crane!", 5, is_letter));
+
+        let mut buffer = GuessBuffer::new(5);
+        assert!(buffer.fill(&['c', 'r', 'a', 'n', 'e']));
+        assert_eq!(5, buffer.cursor());
+        assert!(!buffer.fill(&['c']));
+    }
+
+    #[test]
+    fn test_arrows_stay_within_the_row() {
+        let mut buffer = GuessBuffer::new(5);
+        assert!(!buffer.move_left());
+        (0..10).for_each(|_| {buffer.move_right();});
+        assert_eq!(4, buffer.cursor());
+        let mut buffer = filled("cigar");
+        assert!(!buffer.move_right());
+        assert!(buffer.move_left());
+        assert_eq!(4, buffer.cursor());
+    }
+}
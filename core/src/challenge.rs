@@ -0,0 +1,32 @@
+use crate::cipher::{scramble, unscramble};
+use crate::wordle::{is_valid_guess, parse_word};
+
+/// How far each letter of a challenge is rotated: 7 per position, starting at 11.
+const CHALLENGE_KEY: [u8; 5] = [11, 18, 25, 6, 13];
+
+/// Hides a word in a link so it isn't spoiled at a glance.
+pub fn encode_challenge(word: [char; 5]) -> String {
+    scramble(&word.iter().collect::<String>(), &CHALLENGE_KEY)
+}
+
+/// Reverses `encode_challenge`, accepting only codes that decode to a valid guess.
+pub fn decode_challenge(code: &str) -> Option<[char; 5]> {
+    let chars = parse_word(code)?;
+    let word = unscramble(&chars.iter().collect::<String>(), &CHALLENGE_KEY);
+    if is_valid_guess(&word) { parse_word(&word) } else { None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_challenge_round_trip() {
+        let code = encode_challenge(['c', 'i', 'g', 'a', 'r']);
+        assert_ne!("cigar", code);
+        assert_eq!(Some(['c', 'i', 'g', 'a', 'r']), decode_challenge(&code));
+        assert_eq!(Some(['c', 'i', 'g', 'a', 'r']), decode_challenge(&code.to_uppercase()));
+        assert_eq!(None, decode_challenge("zzzzz"));
+        assert_eq!(None, decode_challenge("abc"));
+    }
+}
@@ -0,0 +1,31 @@
+use crate::wordle::is_letter;
+
+/// Hides a word from a glance by rotating each letter by the key's amount for its position. Anything
+/// that isn't a letter is left as it is.
+pub fn scramble(word: &str, key: &[u8]) -> String {
+    word.chars().zip(key.iter().cycle()).map(|(c, &by)| {rotate(c, by)}).collect()
+}
+
+/// Reverses `scramble` with the same key.
+pub fn unscramble(text: &str, key: &[u8]) -> String {
+    text.chars().zip(key.iter().cycle()).map(|(c, &by)| {rotate(c, 26 - by % 26)}).collect()
+}
+
+fn rotate(c: char, by: u8) -> char {
+    if is_letter(c) { ((c as u8 - b'a' + by % 26) % 26 + b'a') as char } else { c }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scramble_round_trip() {
+        let key = [1, 25, 26];
+        assert_eq!("bzg-c", scramble("aag-d", &key));
+        assert_eq!("aag-d", unscramble(&scramble("aag-d", &key), &key));
+        let large = [255, 230, 27];
+        assert_eq!("uva", scramble("zzz", &large));
+        assert_eq!("zzz", unscramble("uva", &large));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::session::MAX_GUESSES;
+use crate::solver::{solve, Strategy};
+use crate::wordle::LetterHint;
+
+pub const SOLVE_LIMIT: usize = 20;
+
+pub fn turns_to_solve(answer: &str, strategy: &dyn Strategy) -> Option<usize> {
+    let path = solve(answer, strategy, SOLVE_LIMIT);
+    match path.last() {
+        Some(hints) if hints.iter().all(|(_, h)| {*h == LetterHint::Correct}) => Some(path.len()),
+        _ => None,
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Evaluation {
+    pub games: usize,
+    pub solved: usize,
+    pub total_guesses: usize,
+    pub distribution: Vec<usize>,
+    pub failures: Vec<&'static str>,
+    pub worst: Vec<(&'static str, usize)>,
+}
+
+impl Evaluation {
+    pub fn from_results(results: impl IntoIterator<Item = (&'static str, Option<usize>)>, worst_count: usize) -> Self {
+        let mut evaluation = Self {
+            games: 0,
+            solved: 0,
+            total_guesses: 0,
+            distribution: vec![0; MAX_GUESSES],
+            failures: vec![],
+            worst: vec![],
+        };
+        for (answer, turns) in results {
+            evaluation.games += 1;
+            match turns {
+                Some(turns) => {
+                    evaluation.solved += 1;
+                    evaluation.total_guesses += turns;
+                    if evaluation.distribution.len() < turns {
+                        evaluation.distribution.resize(turns, 0);
+                    }
+                    evaluation.distribution[turns - 1] += 1;
+                    if turns > MAX_GUESSES {
+                        evaluation.failures.push(answer);
+                    }
+                    evaluation.worst.push((answer, turns));
+                },
+                None => evaluation.failures.push(answer),
+            }
+        }
+        evaluation.failures.sort_unstable();
+        evaluation.worst.sort_by(|(a, a_turns), (b, b_turns)| {b_turns.cmp(a_turns).then(a.cmp(b))});
+        evaluation.worst.truncate(worst_count);
+        evaluation
+    }
+
+    pub fn average(&self) -> f64 {
+        match self.solved {
+            0 => 0.0,
+            solved => self.total_guesses as f64 / solved as f64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::FirstCandidate;
+
+    #[test]
+    fn test_from_results() {
+        let evaluation = Evaluation::from_results(vec![
+            ("cigar", Some(3)),
+            ("rebut", Some(4)),
+            ("sissy", Some(8)),
+            ("humph", None),
+            ("awake", Some(3)),
+        ], 2);
+        assert_eq!(5, evaluation.games);
+        assert_eq!(4, evaluation.solved);
+        assert_eq!(4.5, evaluation.average());
+        assert_eq!(vec![0, 0, 2, 1, 0, 0, 0, 1], evaluation.distribution);
+        assert_eq!(vec!["humph", "sissy"], evaluation.failures);
+        assert_eq!(vec![("sissy", 8), ("rebut", 4)], evaluation.worst);
+    }
+
+    #[test]
+    fn test_turns_to_solve() {
+        assert_eq!(Some(1), turns_to_solve("aback", &FirstCandidate));
+        assert_eq!(None, turns_to_solve("zzzzz", &FirstCandidate));
+    }
+}
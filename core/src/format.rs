@@ -0,0 +1,273 @@
+//! Serde support, behind the `serde` feature. Values serialize to the same text the game already
+//! stores and sends, e.g. a score is its leaderboard line and a hint is its symbol, so JSON written
+//! by one tool reads the same everywhere. Sessions are the one nested layout and carry `version`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Formatter;
+use serde::de::{Error, IgnoredAny, MapAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::hint::{Hint, HintKind};
+use crate::history::GameRecord;
+use crate::key::Key;
+use crate::leaderboard::Score;
+use crate::puzzle::restore_puzzle;
+use crate::race::{ClientMessage, RaceResult, ServerMessage};
+use crate::session::{GameSession, GameStatus};
+use crate::stats::Stats;
+use crate::wordle::{LetterHint, Wordle};
+
+/// The layout of saved sessions. Bump it when the layout changes, and keep reading the older ones.
+pub const FORMAT_VERSION: u64 = 1;
+
+/// For types that already have a text format through `Display` and `FromStr`.
+macro_rules! serde_via_str {
+    ($($ty:ty),*) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let text = String::deserialize(deserializer)?;
+                text.parse().map_err(|_| {D::Error::custom(format!("invalid {}: `{}`", stringify!($ty), text))})
+            }
+        }
+    )*};
+}
+
+serde_via_str!(Stats, Score, GameRecord, Hint, ClientMessage, ServerMessage, RaceResult);
+
+/// A hint is its symbol, as in `gy..g`.
+impl Serialize for LetterHint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_char(self.symbol())
+    }
+}
+
+impl<'de> Deserialize<'de> for LetterHint {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = char::deserialize(deserializer)?;
+        LetterHint::from_symbol(symbol).ok_or_else(|| {D::Error::custom(format!("invalid hint: `{}`", symbol))})
+    }
+}
+
+/// A key is named as in a browser's `KeyboardEvent.key`, which is also what it parses from.
+impl Serialize for Key {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Key::Letter(c) => serializer.serialize_char(*c),
+            Key::Enter => serializer.serialize_str("Enter"),
+            Key::Backspace => serializer.serialize_str("Backspace"),
+            Key::Left => serializer.serialize_str("ArrowLeft"),
+            Key::Right => serializer.serialize_str("ArrowRight"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(|_| {D::Error::custom(format!("invalid key: `{}`", name))})
+    }
+}
+
+impl Serialize for GameStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            GameStatus::InProgress => "in_progress",
+            GameStatus::Won => "won",
+            GameStatus::Lost => "lost",
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for GameStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "in_progress" => Ok(GameStatus::InProgress),
+            "won" => Ok(GameStatus::Won),
+            "lost" => Ok(GameStatus::Lost),
+            other => Err(D::Error::custom(format!("invalid status: `{}`", other))),
+        }
+    }
+}
+
+impl Serialize for HintKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            HintKind::Letter => "letter",
+            HintKind::Eliminate => "eliminate",
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for HintKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "letter" => Ok(HintKind::Letter),
+            "eliminate" => Ok(HintKind::Eliminate),
+            other => Err(D::Error::custom(format!("invalid hint kind: `{}`", other))),
+        }
+    }
+}
+
+/// A puzzle is its state line, so a fixed answer stays scrambled.
+impl Serialize for Wordle {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.state())
+    }
+}
+
+impl<'de> Deserialize<'de> for Wordle {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = String::deserialize(deserializer)?;
+        Wordle::from_state(&state).ok_or_else(|| {D::Error::custom(format!("invalid puzzle: `{}`", state))})
+    }
+}
+
+/// A guess and its colours, e.g. `{"guess": "crane", "hints": "gy..."}`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Row(pub Vec<(char, LetterHint)>);
+
+impl Serialize for Row {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut row = serializer.serialize_struct("Row", 2)?;
+        row.serialize_field("guess", &self.0.iter().map(|&(c, _)| {c}).collect::<String>())?;
+        row.serialize_field("hints", &self.0.iter().map(|(_, hint)| {hint.symbol()}).collect::<String>())?;
+        row.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Row {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = BTreeMap::<String, String>::deserialize(deserializer)?;
+        let field = |name: &'static str| {fields.get(name).ok_or_else(|| {D::Error::missing_field(name)})};
+        let (guess, hints) = (field("guess")?, field("hints")?);
+        let hints = hints.chars().map(LetterHint::from_symbol).collect::<Option<Vec<_>>>()
+            .filter(|hints| {hints.len() == guess.chars().count()})
+            .ok_or_else(|| {D::Error::custom(format!("invalid hints for `{}`: `{}`", guess, hints))})?;
+        Ok(Row(guess.chars().zip(hints).collect()))
+    }
+}
+
+/// `{"version": 1, "puzzle": "wordle …", "max_guesses": 6, "guesses": [rows], "hints": ["letter 0 c"]}`.
+/// The letters being typed aren't kept.
+impl Serialize for GameSession {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut session = serializer.serialize_struct("GameSession", 5)?;
+        session.serialize_field("version", &FORMAT_VERSION)?;
+        session.serialize_field("puzzle", &self.puzzle_state())?;
+        session.serialize_field("max_guesses", &self.max_guesses())?;
+        session.serialize_field("guesses", &self.guesses().iter().map(|row| {Row(row.clone())}).collect::<Vec<_>>())?;
+        session.serialize_field("hints", self.hints())?;
+        session.end()
+    }
+}
+
+struct SessionVisitor;
+
+impl<'de> Visitor<'de> for SessionVisitor {
+    type Value = GameSession;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a saved game session")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<GameSession, A::Error> {
+        let (mut version, mut puzzle, mut max_guesses, mut guesses, mut hints) = (None, None, None, None, None);
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "version" => version = Some(map.next_value::<u64>()?),
+                "puzzle" => puzzle = Some(map.next_value::<String>()?),
+                "max_guesses" => max_guesses = Some(map.next_value::<usize>()?),
+                "guesses" => guesses = Some(map.next_value::<Vec<Row>>()?),
+                "hints" => hints = Some(map.next_value::<Vec<Hint>>()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                },
+            }
+        }
+        match version.ok_or_else(|| {A::Error::missing_field("version")})? {
+            1..=FORMAT_VERSION => (),
+            version => return Err(A::Error::custom(format!("unsupported session version {}", version))),
+        }
+        let state = puzzle.ok_or_else(|| {A::Error::missing_field("puzzle")})?;
+        let puzzle = restore_puzzle(&state).ok_or_else(|| {A::Error::custom(format!("invalid puzzle: `{}`", state))})?;
+        GameSession::restore(
+            puzzle,
+            guesses.unwrap_or_default().into_iter().map(|row| {row.0}).collect(),
+            hints.unwrap_or_default(),
+            max_guesses.ok_or_else(|| {A::Error::missing_field("max_guesses")})?,
+        ).ok_or_else(|| {A::Error::custom("the guesses don't fit the puzzle")})
+    }
+}
+
+impl<'de> Deserialize<'de> for GameSession {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(SessionVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    fn round_trip<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> T {
+        serde_json::from_value(serde_json::to_value(value).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_values_use_their_text_formats() {
+        assert_eq!(json!("g"), serde_json::to_value(LetterHint::Correct).unwrap());
+        assert_eq!(json!(["a", "Enter", "ArrowLeft"]), serde_json::to_value([Key::Letter('a'), Key::Enter, Key::Left]).unwrap());
+        assert_eq!(Key::Right, round_trip(&Key::Right));
+        assert_eq!(json!("in_progress"), serde_json::to_value(GameStatus::InProgress).unwrap());
+        assert_eq!(json!("letter 2 g"), serde_json::to_value(Hint::Letter(2, 'g')).unwrap());
+        let score = "200 ann hard 41200 0 gy..g-ggggg".parse::<Score>().unwrap();
+        assert_eq!(json!("200 ann hard 41200 0 gy..g-ggggg"), serde_json::to_value(&score).unwrap());
+        assert_eq!(json!("guess gy..g"), serde_json::to_value("guess gy..g".parse::<ClientMessage>().unwrap()).unwrap());
+        assert!(serde_json::from_value::<Score>(json!("200 ann easy 1 ggggg")).is_err());
+        assert!(serde_json::from_value::<LetterHint>(json!("?")).is_err());
+    }
+
+    #[test]
+    fn test_wordle_stays_scrambled() {
+        let wordle = Wordle::new(['c', 'i', 'g', 'a', 'r']);
+        let value = serde_json::to_value(&wordle).unwrap();
+        assert!(!value.as_str().unwrap().contains("cigar"));
+        assert_eq!("cigar", round_trip(&wordle).get_answer());
+        let mut adversarial = Wordle::new_adversarial();
+        adversarial.guess(['c', 'r', 'a', 'n', 'e']).unwrap();
+        assert_eq!(adversarial.remaining(), round_trip(&adversarial).remaining());
+        assert!(serde_json::from_value::<Wordle>(json!("absurd zzzzz")).is_err());
+    }
+
+    #[test]
+    fn test_sessions_round_trip() {
+        let mut session = GameSession::new(Wordle::new(['c', 'i', 'g', 'a', 'r']));
+        session.guess(&['c', 'r', 'a', 'n', 'e']).unwrap();
+        session.hint(HintKind::Letter).unwrap();
+        let value = serde_json::to_value(&session).unwrap();
+        assert_eq!(json!(1), value["version"]);
+        assert_eq!("crane", value["guesses"][0]["guess"]);
+
+        let mut restored = serde_json::from_value::<GameSession>(value.clone()).unwrap();
+        assert_eq!(session.guesses(), restored.guesses());
+        assert_eq!(session.hints(), restored.hints());
+        assert_eq!(GameStatus::InProgress, restored.status());
+        restored.guess(&['c', 'i', 'g', 'a', 'r']).unwrap();
+        assert_eq!(GameStatus::Won, restored.status());
+
+        let mut newer = value.clone();
+        newer["version"] = json!(FORMAT_VERSION + 1);
+        assert!(serde_json::from_value::<GameSession>(newer).is_err());
+        let mut too_long = value;
+        too_long["max_guesses"] = json!(0);
+        assert!(serde_json::from_value::<GameSession>(too_long).is_err());
+    }
+}
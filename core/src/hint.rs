@@ -0,0 +1,81 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use crate::wordle::{KeyStates, LetterHint};
+
+/// How many absent keys a single elimination hint greys out.
+pub const ELIMINATED_KEYS: usize = 3;
+
+const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz0123456789+-*/=";
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum HintKind {
+    Letter,
+    Eliminate,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Hint {
+    /// The answer has this character at this position.
+    Letter(usize, char),
+    /// None of these characters appear in the answer.
+    Eliminate(Vec<char>),
+}
+
+impl Hint {
+    pub fn key_hints(&self) -> Vec<(char, LetterHint)> {
+        match self {
+            Self::Letter(_, c) => vec![(*c, LetterHint::Correct)],
+            Self::Eliminate(chars) => chars.iter().map(|&c| {(c, LetterHint::Absent)}).collect(),
+        }
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Letter(i, c) => write!(f, "letter {} {}", i, c),
+            Self::Eliminate(chars) => write!(f, "eliminate {}", chars.iter().collect::<String>()),
+        }
+    }
+}
+
+impl FromStr for Hint {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valid = |c: char| {ALPHABET.contains(c)};
+        match s.split_whitespace().collect::<Vec<_>>().as_slice() {
+            ["letter", i, c] => match c.chars().collect::<Vec<_>>().as_slice() {
+                [c] if valid(*c) => Ok(Self::Letter(i.parse().map_err(|_| ())?, *c)),
+                _ => Err(()),
+            },
+            ["eliminate", chars] if chars.chars().all(valid) => Ok(Self::Eliminate(chars.chars().collect())),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Picks the leftmost position not yet known to be correct, or the first few keys that are
+/// absent from the answer but not yet marked as such. `None` when there is nothing left to give away.
+pub fn find_hint(
+    kind: HintKind,
+    answer: &str,
+    solved: &[bool],
+    key_states: &KeyStates,
+    accepts: impl Fn(char) -> bool,
+) -> Option<Hint> {
+    match kind {
+        HintKind::Letter => answer.chars()
+            .enumerate()
+            .find(|&(i, _)| {!solved.get(i).copied().unwrap_or(false)})
+            .map(|(i, c)| {Hint::Letter(i, c)}),
+        HintKind::Eliminate => {
+            let chars = ALPHABET.chars()
+                .filter(|&c| {accepts(c) && !answer.contains(c) && key_states.get(c).is_none()})
+                .take(ELIMINATED_KEYS)
+                .collect::<Vec<_>>();
+            if chars.is_empty() { None } else { Some(Hint::Eliminate(chars)) }
+        },
+    }
+}
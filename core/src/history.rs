@@ -0,0 +1,162 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use crate::multi::MultiSession;
+use crate::versus::encode_result;
+use crate::wordle::LetterHint;
+
+/// A finished game as kept in the history log, with enough of it to replay the board row by row.
+#[derive(Clone, PartialEq, Debug)]
+pub struct GameRecord {
+    /// The mode it was played in, e.g. `daily` or `archive`.
+    pub mode: String,
+    /// Which puzzle of that mode, e.g. its number or a challenge code, or `-` when there is nothing to name.
+    pub puzzle: String,
+    pub guesses: Vec<String>,
+    /// Each board's rows as colours. A board solved early has fewer rows than there are guesses.
+    pub boards: Vec<Vec<Vec<LetterHint>>>,
+    pub hints: usize,
+    /// Milliseconds since the epoch.
+    pub started: u64,
+    pub finished: u64,
+}
+
+impl GameRecord {
+    pub fn new(mode: &str, puzzle: &str, session: &MultiSession, started: u64, finished: u64) -> Self {
+        let boards = session.boards();
+        let longest = boards.iter().max_by_key(|board| {board.guesses().len()}).map_or(&[][..], |board| {board.guesses()});
+        Self {
+            mode: mode.to_string(),
+            puzzle: puzzle.to_string(),
+            guesses: longest.iter().map(|row| {row.iter().map(|&(c, _)| {c}).collect()}).collect(),
+            boards: boards.iter()
+                .map(|board| {board.guesses().iter().map(|row| {row.iter().map(|&(_, hint)| {hint}).collect()}).collect()})
+                .collect(),
+            hints: session.hints_used(),
+            started,
+            finished,
+        }
+    }
+
+    /// Whether every board ended on a solved row.
+    pub fn is_won(&self) -> bool {
+        self.boards.iter().all(|rows| {
+            rows.last().is_some_and(|row| {row.iter().all(|&hint| {hint == LetterHint::Correct})})
+        })
+    }
+
+    /// The guesses the game took: the most rows any board needed.
+    pub fn turns(&self) -> usize {
+        self.boards.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// A board's rows with their letters put back, for replaying it.
+    pub fn rows(&self, board: usize) -> Vec<Vec<(char, LetterHint)>> {
+        self.boards.get(board).map_or(vec![], |rows| {
+            rows.iter()
+                .zip(&self.guesses)
+                .map(|(hints, guess)| {guess.chars().zip(hints.iter().copied()).collect()})
+                .collect()
+        })
+    }
+}
+
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f, "{} {} {} {} {} {}",
+            self.started, self.finished, self.mode, self.puzzle, self.hints, self.guesses.join(","),
+        )?;
+        self.boards.iter().try_for_each(|rows| {write!(f, " {}", encode_result(rows))})
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = ();
+
+    /// Reads a line like `1700000000000 1700000095000 daily 200 0 crane,cigar gy..y-ggggg`, with one
+    /// result per board at the end.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_whitespace().collect::<Vec<_>>().as_slice() {
+            [started, finished, mode, puzzle, hints, guesses, boards @ ..] if !boards.is_empty() => {
+                let guesses = guesses.split(',').map(str::to_string).collect::<Vec<_>>();
+                let boards = boards.iter()
+                    .map(|rows| {
+                        rows.split('-')
+                            .map(|row| {row.chars().map(LetterHint::from_symbol).collect::<Option<Vec<_>>>()})
+                            .collect::<Option<Vec<_>>>()
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or(())?;
+                // Every row has to line up with the guess it colours.
+                let fits = boards.iter().all(|rows| {
+                    rows.len() <= guesses.len()
+                        && rows.iter().zip(&guesses).all(|(row, guess)| {!guess.is_empty() && row.len() == guess.chars().count()})
+                });
+                if !fits {
+                    return Err(());
+                }
+                Ok(Self {
+                    mode: mode.to_string(),
+                    puzzle: puzzle.to_string(),
+                    guesses,
+                    boards,
+                    hints: hints.parse().map_err(|_| ())?,
+                    started: started.parse().map_err(|_| ())?,
+                    finished: finished.parse().map_err(|_| ())?,
+                })
+            },
+            _ => Err(()),
+        }
+    }
+}
+
+/// Reads a history log, one record per line, skipping lines that don't parse.
+pub fn parse_history(log: &str) -> Vec<GameRecord> {
+    log.lines().filter_map(|line| {line.parse().ok()}).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::GameSession;
+    use crate::wordle::Wordle;
+
+    fn guess(session: &mut MultiSession, word: &str) {
+        word.chars().for_each(|c| {session.push_letter(c);});
+        session.submit().unwrap();
+    }
+
+    #[test]
+    fn test_record_from_session() {
+        let mut session = MultiSession::new(vec![
+            GameSession::with_max_guesses(Wordle::new(['c', 'i', 'g', 'a', 'r']), 7),
+            GameSession::with_max_guesses(Wordle::new(['r', 'e', 'b', 'u', 't']), 7),
+        ]);
+        guess(&mut session, "cigar");
+        guess(&mut session, "rebut");
+        let record = GameRecord::new("dordle", "200", &session, 1_000, 61_000);
+        assert!(record.is_won());
+        assert_eq!(2, record.turns());
+        assert_eq!(vec!["cigar", "rebut"], record.guesses);
+        assert_eq!(1, record.rows(0).len());
+        assert_eq!(('r', LetterHint::Correct), record.rows(1)[1][0]);
+        assert!(record.rows(2).is_empty());
+
+        let line = "1000 61000 dordle 200 0 cigar,rebut ggggg ....y-ggggg";
+        assert_eq!(line, record.to_string());
+        assert_eq!(Ok(record), line.parse());
+    }
+
+    #[test]
+    fn test_invalid_records() {
+        assert_eq!(Err(()), "1000 61000 daily 200 0 cigar".parse::<GameRecord>());
+        assert_eq!(Err(()), "1000 61000 daily 200 0 cigar ggggg-ggggg".parse::<GameRecord>());
+        assert_eq!(Err(()), "1000 61000 daily 200 0 cigar gggg".parse::<GameRecord>());
+        assert_eq!(Err(()), "soon 61000 daily 200 0 cigar ggggg".parse::<GameRecord>());
+        let log = "1000 61000 daily 200 0 crane,cigar y....-ggggg\nnonsense\n";
+        let history = parse_history(log);
+        assert_eq!(1, history.len());
+        assert!(!GameRecord { boards: vec![vec![]], ..history[0].clone() }.is_won());
+    }
+}
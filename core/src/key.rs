@@ -0,0 +1,87 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum Key {
+    Letter(char),
+    Enter,
+    Backspace,
+    Left,
+    Right,
+}
+
+impl FromStr for Key {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            _ if s.len() == 1 => match s.chars().nth(0) {
+                Some(c@('a' ..= 'z' | '0' ..= '9' | '+' | '-' | '*' | '/' | '=')) => Ok(Key::Letter(c)),
+                _ => Err(()),
+            },
+            "Enter" => Ok(Key::Enter),
+            "Backspace" => Ok(Key::Backspace),
+            "ArrowLeft" => Ok(Key::Left),
+            "ArrowRight" => Ok(Key::Right),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Key {
+    /// Maps a browser `KeyboardEvent`'s `key` and `code`, ignoring case. Layouts that don't type
+    /// Latin letters fall back to the physical key, so a Cyrillic `ф` still means `a`.
+    pub fn from_key_event(key: &str, code: &str) -> Option<Key> {
+        let mut chars = key.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii() => c.to_ascii_lowercase().to_string().parse().ok(),
+            (Some(_), None) => Self::from_code(code),
+            _ => key.parse().ok(),
+        }
+    }
+
+    fn from_code(code: &str) -> Option<Key> {
+        code.strip_prefix("Key")
+            .or_else(|| {code.strip_prefix("Digit")})
+            .and_then(|name| {Self::from_key_event(name, "")})
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Letter(l) => l.to_string(),
+            k => format!("{:?}", k),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(Ok(Key::Letter('q')), "q".parse());
+        assert_eq!(Ok(Key::Enter), "Enter".parse());
+        assert_eq!(Ok(Key::Backspace), "Backspace".parse());
+        assert_eq!(Ok(Key::Left), "ArrowLeft".parse());
+        assert_eq!(Err(()), "Shift".parse::<Key>());
+        assert_eq!(Ok(Key::Letter('1')), "1".parse());
+        assert_eq!(Ok(Key::Letter('=')), "=".parse());
+        assert_eq!(Err(()), "A".parse::<Key>());
+        assert_eq!(Err(()), "%".parse::<Key>());
+    }
+
+    #[test]
+    fn test_keyboard_events() {
+        assert_eq!(Some(Key::Letter('a')), Key::from_key_event("A", "KeyA"));
+        assert_eq!(Some(Key::Enter), Key::from_key_event("Enter", "Enter"));
+        assert_eq!(Some(Key::Letter('f')), Key::from_key_event("а", "KeyF"));
+        assert_eq!(Some(Key::Letter('7')), Key::from_key_event("ç", "Digit7"));
+        assert_eq!(None, Key::from_key_event("Process", "KeyA"));
+        assert_eq!(None, Key::from_key_event("Shift", "ShiftLeft"));
+        assert_eq!(None, Key::from_key_event("é", "Semicolon"));
+    }
+}
@@ -0,0 +1,123 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use crate::race::is_valid_name;
+use crate::session::GameStatus;
+use crate::versus::{decode_result, encode_result, result_status};
+use crate::wordle::LetterHint;
+
+/// A player's result for one daily puzzle, as posted to a leaderboard. Guesses are sent as their
+/// colours only, so a board can be ranked and drawn without spoiling the answer.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Score {
+    pub player: String,
+    pub puzzle: i64,
+    pub rows: Vec<Vec<LetterHint>>,
+    pub hard_mode: bool,
+    pub millis: u64,
+    /// How many hints the player took.
+    pub hints: usize,
+}
+
+impl Score {
+    pub fn is_won(&self) -> bool {
+        result_status(&self.rows) == GameStatus::Won
+    }
+
+    /// Solved boards first, then fewer hints, then fewer guesses, then hard mode, then the faster player.
+    fn rank_key(&self) -> (bool, usize, usize, bool, u64) {
+        (!self.is_won(), self.hints, self.rows.len(), !self.hard_mode, self.millis)
+    }
+}
+
+/// Sorts scores best first.
+pub fn rank_scores(scores: &mut [Score]) {
+    scores.sort_by_key(|score| {score.rank_key()});
+}
+
+/// Keeps the first score each player posted for a puzzle.
+pub fn is_new_score(scores: &[Score], score: &Score) -> bool {
+    !scores.iter().any(|other| {other.puzzle == score.puzzle && other.player == score.player})
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f, "{} {} {} {} {} {}",
+            self.puzzle, self.player, if self.hard_mode { "hard" } else { "normal" }, self.millis, self.hints,
+            encode_result(&self.rows),
+        )
+    }
+}
+
+impl FromStr for Score {
+    type Err = ();
+
+    /// Reads a line like `200 ann normal 41200 0 gy..g-ggggg`. Lines kept from before hints were sent
+    /// have no hint count, and count as taking none.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = s.split_whitespace().collect::<Vec<_>>();
+        let (fields, hints) = match fields.as_slice() {
+            [puzzle, player, mode, millis, hints, rows] => {
+                (vec![*puzzle, *player, *mode, *millis, *rows], hints.parse().map_err(|_| ())?)
+            },
+            _ => (fields, 0),
+        };
+        match fields.as_slice() {
+            [puzzle, player, mode, millis, rows] if is_valid_name(player) => Ok(Self {
+                player: player.to_string(),
+                puzzle: puzzle.parse().map_err(|_| ())?,
+                rows: decode_result(rows).filter(|rows| {result_status(rows) != GameStatus::InProgress}).ok_or(())?,
+                hard_mode: match *mode {
+                    "hard" => true,
+                    "normal" => false,
+                    _ => return Err(()),
+                },
+                millis: millis.parse().map_err(|_| ())?,
+                hints,
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(player: &str, rows: &str, hard_mode: bool, millis: u64) -> Score {
+        format!("200 {} {} {} 0 {}", player, if hard_mode { "hard" } else { "normal" }, millis, rows).parse().unwrap()
+    }
+
+    #[test]
+    fn test_scores_round_trip() {
+        let line = "200 ann hard 41200 1 gy..g-ggggg";
+        let parsed = line.parse::<Score>().unwrap();
+        assert!(parsed.is_won());
+        assert_eq!(1, parsed.hints);
+        assert_eq!(line, parsed.to_string());
+        assert_eq!(Ok(Score { hints: 0, ..parsed }), "200 ann hard 41200 gy..g-ggggg".parse());
+        assert_eq!(Err(()), "200 ann hard 41200 some gy..g-ggggg".parse::<Score>());
+        assert_eq!(Err(()), "200 ann hard 41200 gy..g".parse::<Score>());
+        assert_eq!(Err(()), "200 ann easy 41200 ggggg".parse::<Score>());
+        assert_eq!(Err(()), "200 a@b hard 41200 ggggg".parse::<Score>());
+    }
+
+    #[test]
+    fn test_ranking() {
+        let lost = ["y....", ".y...", "..y..", "...y.", "....y", "gggg."].join("-");
+        let mut scores = vec![
+            score("lost", &lost, true, 1_000),
+            score("slow", "....y-ggggg", false, 90_000),
+            score("hard", "....y-ggggg", true, 95_000),
+            score("fast", "....y-ggggg", false, 30_000),
+            score("lucky", "ggggg", false, 99_000),
+            Score { hints: 1, ..score("helped", "ggggg", true, 1_000) },
+        ];
+        rank_scores(&mut scores);
+        let names = scores.iter().map(|score| {score.player.as_str()}).collect::<Vec<_>>();
+        assert_eq!(vec!["lucky", "hard", "fast", "slow", "helped", "lost"], names);
+        assert!(!is_new_score(&scores, &score("fast", "ggggg", false, 1)));
+        assert!(is_new_score(&scores, &score("dan", "ggggg", false, 1)));
+    }
+}
@@ -0,0 +1,46 @@
+mod backup;
+mod blitz;
+mod buffer;
+mod challenge;
+mod cipher;
+mod evaluation;
+#[cfg(feature = "serde")]
+mod format;
+mod hint;
+mod history;
+mod key;
+mod leaderboard;
+mod multi;
+mod nerdle;
+mod puzzle;
+mod race;
+mod session;
+mod share;
+mod solver;
+mod stats;
+mod timer;
+mod versus;
+mod wordle;
+
+pub use backup::{check_backup_version, Backup, BackupError, BACKUP_VERSION};
+pub use blitz::{Blitz, BLITZ_MILLIS};
+pub use buffer::{sanitize_paste, GuessBuffer, PasteError};
+pub use challenge::{decode_challenge, encode_challenge};
+pub use evaluation::{turns_to_solve, Evaluation, SOLVE_LIMIT};
+#[cfg(feature = "serde")]
+pub use format::{Row, FORMAT_VERSION};
+pub use hint::{Hint, HintKind, ELIMINATED_KEYS};
+pub use history::{parse_history, GameRecord};
+pub use key::Key;
+pub use leaderboard::{is_new_score, rank_scores, Score};
+pub use multi::{max_guesses_for_boards, MultiSession};
+pub use nerdle::{equations, evaluate, is_valid_equation, Nerdle, EQUATION_LENGTH};
+pub use puzzle::{restore_puzzle, Puzzle};
+pub use race::{is_valid_name, race_winner, ClientMessage, RaceResult, ServerMessage};
+pub use session::{GameSession, GameStatus, GuessError, ADVERSARIAL_MAX_GUESSES, MAX_GUESSES};
+pub use share::share_text;
+pub use stats::Stats;
+pub use solver::{solve, FirstCandidate, MaxPartitions, Solver, Strategy};
+pub use timer::{format_duration, Clock, ManualClock, Stopwatch, SystemClock};
+pub use versus::{decode_result, encode_result, result_status, versus_winner, Versus, VersusPhase};
+pub use wordle::{answers, is_letter, is_valid_guess, parse_word, puzzle_number, score, today_puzzle_number, word_for_puzzle, GuessResult, KeyStates, LetterHint, Wordle};
@@ -0,0 +1,248 @@
+use crate::buffer::{sanitize_paste, GuessBuffer, PasteError};
+use crate::hint::{Hint, HintKind};
+use crate::key::Key;
+use crate::session::{GameSession, GameStatus, GuessError};
+use crate::wordle::{today_puzzle_number, KeyStates, Wordle};
+
+pub fn max_guesses_for_boards(boards: usize) -> usize {
+    boards + 5
+}
+
+pub struct MultiSession {
+    boards: Vec<GameSession>,
+    current_guess: GuessBuffer,
+    turns: usize,
+}
+
+impl MultiSession {
+    pub fn new(boards: Vec<GameSession>) -> Self {
+        let width = boards.first().map_or(5, |board| {board.width()});
+        // Boards picked up from a saved game have already been played on.
+        let turns = boards.iter().map(|board| {board.guesses().len()}).max().unwrap_or(0);
+        Self {
+            boards,
+            current_guess: GuessBuffer::new(width),
+            turns,
+        }
+    }
+
+    pub fn single(session: GameSession) -> Self {
+        Self::new(vec![session])
+    }
+
+    pub fn daily(boards: usize) -> Self {
+        Self::for_puzzle(today_puzzle_number(), boards)
+    }
+
+    pub fn for_puzzle(number: i64, boards: usize) -> Self {
+        let max_guesses = max_guesses_for_boards(boards);
+        Self::new((0..boards)
+            .map(|i| {
+                let wordle = Wordle::new_for_puzzle(number * boards as i64 + i as i64);
+                GameSession::with_max_guesses(wordle, max_guesses)
+            })
+            .collect())
+    }
+
+    pub fn boards(&self) -> &[GameSession] {
+        self.boards.as_slice()
+    }
+
+    pub fn width(&self) -> usize {
+        self.current_guess.width()
+    }
+
+    pub fn max_guesses(&self) -> usize {
+        self.boards.iter().map(|board| {board.max_guesses()}).max().unwrap_or(0)
+    }
+
+    pub fn turns(&self) -> usize {
+        self.turns
+    }
+
+    pub fn current_guess(&self) -> &GuessBuffer {
+        &self.current_guess
+    }
+
+    /// The answers of the boards that are over.
+    pub fn answers(&self) -> Vec<String> {
+        self.boards.iter().filter_map(|board| {board.answer()}).collect()
+    }
+
+    pub fn status(&self) -> GameStatus {
+        if self.boards.iter().all(|board| {board.status() == GameStatus::Won}) {
+            GameStatus::Won
+        } else if self.boards.iter().any(|board| {board.status() == GameStatus::Lost}) {
+            GameStatus::Lost
+        } else {
+            GameStatus::InProgress
+        }
+    }
+
+    pub fn hints_used(&self) -> usize {
+        self.boards.iter().map(|board| {board.hints().len()}).sum()
+    }
+
+    pub fn can_hint(&self) -> bool {
+        self.boards.iter().any(|board| {board.can_hint()})
+    }
+
+    /// Hints go to the leftmost board that is still being played.
+    pub fn hint(&mut self, kind: HintKind) -> Option<Hint> {
+        self.boards.iter_mut()
+            .find(|board| {board.can_hint()})
+            .and_then(|board| {board.hint(kind)})
+    }
+
+    pub fn key_states_revealed(&self, revealed_tiles: usize) -> Vec<KeyStates> {
+        self.boards.iter()
+            .map(|board| {
+                if board.guesses().len() == self.turns {
+                    board.key_states_revealed(revealed_tiles)
+                } else {
+                    board.key_states()
+                }
+            })
+            .collect()
+    }
+
+    pub fn press(&mut self, key: Key) -> Result<bool, GuessError> {
+        match key {
+            Key::Letter(c) => Ok(self.push_letter(c)),
+            Key::Backspace => Ok(self.pop_letter()),
+            Key::Enter => self.submit().map(|_| {true}),
+            Key::Left => Ok(self.status() == GameStatus::InProgress && self.current_guess.move_left()),
+            Key::Right => Ok(self.status() == GameStatus::InProgress && self.current_guess.move_right()),
+        }
+    }
+
+    pub fn push_letter(&mut self, c: char) -> bool {
+        let accepted = self.boards.iter().all(|board| {board.accepts(c)});
+        self.status() == GameStatus::InProgress && accepted && self.current_guess.insert(c)
+    }
+
+    pub fn pop_letter(&mut self) -> bool {
+        self.status() == GameStatus::InProgress && self.current_guess.delete()
+    }
+
+    pub fn paste(&mut self, text: &str) -> Result<bool, PasteError> {
+        if self.status() != GameStatus::InProgress {
+            return Ok(false);
+        }
+        let chars = sanitize_paste(text, self.width(), |c| {self.boards.iter().all(|board| {board.accepts(c)})})?;
+        Ok(self.current_guess.fill(&chars))
+    }
+
+    pub fn move_cursor(&mut self, i: usize) -> bool {
+        self.status() == GameStatus::InProgress && self.current_guess.move_to(i)
+    }
+
+    pub fn submit(&mut self) -> Result<(), GuessError> {
+        if self.status() != GameStatus::InProgress {
+            return Err(GuessError::GameOver);
+        }
+        if !self.current_guess.is_full() {
+            return Err(GuessError::NotEnoughLetters);
+        }
+        let guess = self.current_guess.to_vec();
+        let guess = guess.as_slice();
+        if !self.boards.iter().any(|board| {board.status() == GameStatus::InProgress && board.validate(guess)}) {
+            return Err(GuessError::NotInWordList);
+        }
+        self.boards.iter_mut()
+            .filter(|board| {board.status() == GameStatus::InProgress})
+            .try_for_each(|board| {board.guess(guess).map(|_| ())})?;
+        self.current_guess.clear();
+        self.turns += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordle::word_for_puzzle;
+
+    fn type_word(session: &mut MultiSession, word: &str) {
+        word.chars().for_each(|c| {
+            session.press(Key::Letter(c)).unwrap();
+        });
+    }
+
+    fn dordle() -> MultiSession {
+        MultiSession::new(vec![
+            GameSession::with_max_guesses(Wordle::new(['c', 'i', 'g', 'a', 'r']), 7),
+            GameSession::with_max_guesses(Wordle::new(['r', 'e', 'b', 'u', 't']), 7),
+        ])
+    }
+
+    #[test]
+    fn test_solved_boards_freeze() {
+        let mut session = dordle();
+        type_word(&mut session, "cigar");
+        session.submit().unwrap();
+        type_word(&mut session, "crane");
+        session.submit().unwrap();
+        assert_eq!(1, session.boards()[0].guesses().len());
+        assert_eq!(2, session.boards()[1].guesses().len());
+        assert_eq!(GameStatus::Won, session.boards()[0].status());
+        assert_eq!(GameStatus::InProgress, session.status());
+
+        type_word(&mut session, "rebut");
+        session.submit().unwrap();
+        assert_eq!(GameStatus::Won, session.status());
+        assert_eq!(3, session.turns());
+    }
+
+    #[test]
+    fn test_loses_when_any_board_runs_out() {
+        let mut session = dordle();
+        for _ in 0..6 {
+            type_word(&mut session, "cigar");
+            session.submit().unwrap_or_default();
+            type_word(&mut session, "crane");
+            if session.submit().is_err() {
+                break;
+            }
+        }
+        assert_eq!(GameStatus::Lost, session.status());
+        assert_eq!(Err(GuessError::GameOver), session.submit());
+    }
+
+    #[test]
+    fn test_rejects_unknown_words_without_guessing() {
+        let mut session = dordle();
+        type_word(&mut session, "xxxxx");
+        assert_eq!(Err(GuessError::NotInWordList), session.submit());
+        assert_eq!(0, session.turns());
+        assert!(session.boards().iter().all(|board| {board.guesses().is_empty()}));
+    }
+
+    #[test]
+    fn test_paste_fills_the_row() {
+        let mut session = dordle();
+        type_word(&mut session, "xy");
+        assert_eq!(Ok(true), session.paste("Cigar"));
+        assert_eq!(vec!['c', 'i', 'g', 'a', 'r'], session.current_guess().to_vec());
+        assert_eq!(Err(PasteError::InvalidCharacter('1')), session.paste("c1g4r"));
+        assert_eq!(Err(PasteError::WrongLength), session.paste("cig"));
+        session.submit().unwrap();
+        assert_eq!(GameStatus::Won, session.boards()[0].status());
+    }
+
+    #[test]
+    fn test_daily_variants() {
+        let mut session = MultiSession::for_puzzle(0, 4);
+        assert!(session.answers().is_empty());
+        let words = (0..4).map(word_for_puzzle).collect::<Vec<_>>();
+        for word in &words {
+            type_word(&mut session, word);
+            session.submit().unwrap();
+        }
+        assert_eq!(GameStatus::Won, session.status());
+        assert_eq!(words, session.answers());
+        assert_eq!(9, session.max_guesses());
+        assert_eq!(13, MultiSession::for_puzzle(0, 8).max_guesses());
+        assert_eq!(7, MultiSession::for_puzzle(0, 2).max_guesses());
+    }
+}
@@ -0,0 +1,231 @@
+use std::sync::OnceLock;
+use crate::puzzle::Puzzle;
+use crate::session::GuessError;
+use crate::wordle::{score, LetterHint};
+
+pub const EQUATION_LENGTH: usize = 8;
+const OPERATORS: [char; 4] = ['+', '-', '*', '/'];
+
+pub struct Nerdle {
+    answer: String,
+}
+
+impl Nerdle {
+    pub fn new(answer: &str) -> Self {
+        Self {
+            answer: answer.into(),
+        }
+    }
+
+    pub fn new_for_puzzle(number: i64) -> Self {
+        let equations = equations();
+        let index = (number * 7919).rem_euclid(equations.len() as i64) as usize;
+        Self::new(equations[index].as_str())
+    }
+}
+
+impl Puzzle for Nerdle {
+    fn width(&self) -> usize {
+        EQUATION_LENGTH
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        c.is_ascii_digit() || OPERATORS.contains(&c) || c == '='
+    }
+
+    fn validate(&self, input: &[char]) -> bool {
+        let equation = input.iter().collect::<String>();
+        equation == self.answer || is_valid_equation(equation.as_str())
+    }
+
+    fn answer(&self) -> String {
+        self.answer.clone()
+    }
+
+    fn state(&self) -> String {
+        format!("nerdle {}", self.answer)
+    }
+
+    fn guess(&mut self, input: &[char]) -> Result<Vec<(char, LetterHint)>, GuessError> {
+        if !self.validate(input) {
+            return Err(GuessError::NotInWordList);
+        }
+        let input: [char; EQUATION_LENGTH] = input.try_into().map_err(|_| {GuessError::NotInWordList})?;
+        let hints = score(self.answer.as_str(), input);
+        Ok(input.iter().copied().zip(hints).collect())
+    }
+}
+
+fn parse_number(digits: &str) -> Option<i64> {
+    match digits.as_bytes() {
+        [] => None,
+        [b'0', _, ..] => None,
+        _ if digits.bytes().all(|b| {b.is_ascii_digit()}) => digits.parse().ok(),
+        _ => None,
+    }
+}
+
+pub fn evaluate(expression: &str) -> Option<i64> {
+    let mut terms = vec![];
+    let mut operators = vec![];
+    let mut rest = expression;
+    loop {
+        let end = rest.find(|c| {OPERATORS.contains(&c)}).unwrap_or(rest.len());
+        terms.push(parse_number(&rest[..end])?);
+        match rest[end..].chars().next() {
+            Some(operator) => {
+                operators.push(operator);
+                rest = &rest[end + 1..];
+            },
+            None => break,
+        }
+    }
+
+    let mut sums = vec![terms[0]];
+    let mut signs = vec![];
+    for (operator, term) in operators.into_iter().zip(terms.into_iter().skip(1)) {
+        match operator {
+            '*' => *sums.last_mut()? *= term,
+            '/' if term == 0 => return None,
+            '/' => {
+                let last = sums.last_mut()?;
+                if *last % term != 0 {
+                    return None;
+                }
+                *last /= term;
+            },
+            sign => {
+                signs.push(sign);
+                sums.push(term);
+            },
+        }
+    }
+    signs.into_iter().zip(sums.iter().skip(1))
+        .try_fold(sums[0], |total, (sign, term)| match sign {
+            '+' => total.checked_add(*term),
+            _ => total.checked_sub(*term),
+        })
+}
+
+pub fn is_valid_equation(equation: &str) -> bool {
+    match equation.split_once('=') {
+        Some((left, right)) if equation.chars().count() == EQUATION_LENGTH => {
+            match (evaluate(left), parse_number(right)) {
+                (Some(value), Some(result)) => value == result,
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+pub fn equations() -> &'static [String] {
+    static EQUATIONS: OnceLock<Vec<String>> = OnceLock::new();
+    EQUATIONS.get_or_init(|| {
+        let numbers = |digits: u32| {10_i64.pow(digits - 1)..10_i64.pow(digits)};
+        let mut equations = vec![];
+        for a_digits in 1..=4 {
+            for b_digits in 1..=(5 - a_digits) {
+                for a in numbers(a_digits) {
+                    for b in numbers(b_digits) {
+                        for op in OPERATORS {
+                            match apply(a, op, b) {
+                                Some(value) if fits(a_digits + b_digits + 1, value) => {
+                                    equations.push(format!("{}{}{}={}", a, op, b, value));
+                                },
+                                _ => (),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        for digits in [[1, 1, 1], [1, 1, 2], [1, 2, 1], [2, 1, 1]] {
+            for a in numbers(digits[0]) {
+                for b in numbers(digits[1]) {
+                    for c in numbers(digits[2]) {
+                        for op1 in OPERATORS {
+                            for op2 in OPERATORS {
+                                let value = match (op1, op2) {
+                                    ('+' | '-', '*' | '/') => apply(b, op2, c).and_then(|bc| {apply(a, op1, bc)}),
+                                    _ => apply(a, op1, b).and_then(|ab| {apply(ab, op2, c)}),
+                                };
+                                match value {
+                                    Some(value) if fits(digits.iter().sum::<u32>() + 2, value) => {
+                                        equations.push(format!("{}{}{}{}{}={}", a, op1, b, op2, c, value));
+                                    },
+                                    _ => (),
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        equations
+    })
+}
+
+fn apply(a: i64, operator: char, b: i64) -> Option<i64> {
+    match operator {
+        '+' => Some(a + b),
+        '-' => Some(a - b),
+        '*' => Some(a * b),
+        _ if b != 0 && a % b == 0 => Some(a / b),
+        _ => None,
+    }
+}
+
+fn fits(expression_length: u32, value: i64) -> bool {
+    value >= 0 && expression_length + 1 + value.to_string().len() as u32 == EQUATION_LENGTH as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordle::LetterHint::{Absent, Correct, Present};
+
+    #[test]
+    fn test_evaluate_respects_precedence() {
+        assert_eq!(Some(14), evaluate("2+3*4"));
+        assert_eq!(Some(1), evaluate("9-4*2"));
+        assert_eq!(Some(4), evaluate("12/3"));
+        assert_eq!(None, evaluate("7/2"));
+        assert_eq!(None, evaluate("5/0"));
+        assert_eq!(None, evaluate("05+1"));
+        assert_eq!(None, evaluate("5+"));
+        assert_eq!(None, evaluate("+5"));
+        assert_eq!(None, evaluate("5**1"));
+    }
+
+    #[test]
+    fn test_valid_equations() {
+        assert!(is_valid_equation("12+35=47"));
+        assert!(is_valid_equation("4*5-8=12"));
+        assert!(!is_valid_equation("12+35=48"));
+        assert!(!is_valid_equation("1+2=3"));
+        assert!(!is_valid_equation("12+35=047"));
+        assert!(!is_valid_equation("12+3=5=9"));
+    }
+
+    #[test]
+    fn test_generated_equations_are_valid() {
+        let equations = equations();
+        assert!(equations.len() > 1000);
+        assert!(equations.iter().all(|e| {is_valid_equation(e)}));
+        assert!(equations.iter().any(|e| {e == "12+35=47"}));
+    }
+
+    #[test]
+    fn test_guess_scores_symbols() {
+        let mut nerdle = Nerdle::new("12+35=47");
+        let guess = "13+42=55".chars().collect::<Vec<char>>();
+        let hints = nerdle.guess(&guess).unwrap();
+        assert_eq!(
+            vec![Correct, Present, Correct, Present, Present, Correct, Present, Absent],
+            hints.iter().map(|(_, h)| {*h}).collect::<Vec<LetterHint>>(),
+        );
+        let invalid = "10+37=48".chars().collect::<Vec<char>>();
+        assert_eq!(Err(GuessError::NotInWordList), nerdle.guess(&invalid));
+    }
+}
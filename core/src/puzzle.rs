@@ -0,0 +1,72 @@
+use crate::nerdle::{is_valid_equation, Nerdle};
+use crate::session::{GuessError, ADVERSARIAL_MAX_GUESSES, MAX_GUESSES};
+use crate::wordle::{is_letter, is_valid_guess, LetterHint, Wordle};
+
+pub trait Puzzle {
+    fn width(&self) -> usize;
+
+    fn max_guesses(&self) -> usize {
+        MAX_GUESSES
+    }
+
+    fn accepts(&self, c: char) -> bool;
+
+    fn validate(&self, input: &[char]) -> bool;
+
+    /// The answer in plain text; callers should only show it once the game is over.
+    fn answer(&self) -> String;
+
+    fn remaining(&self) -> usize {
+        1
+    }
+
+    /// Everything needed to pick the puzzle up again, as a line that `restore_puzzle` reads back.
+    fn state(&self) -> String;
+
+    fn guess(&mut self, input: &[char]) -> Result<Vec<(char, LetterHint)>, GuessError>;
+}
+
+impl Puzzle for Wordle {
+    fn width(&self) -> usize {
+        5
+    }
+
+    fn max_guesses(&self) -> usize {
+        if self.is_adversarial() { ADVERSARIAL_MAX_GUESSES } else { MAX_GUESSES }
+    }
+
+    fn accepts(&self, c: char) -> bool {
+        is_letter(c)
+    }
+
+    fn validate(&self, input: &[char]) -> bool {
+        let word = input.iter().collect::<String>();
+        input.len() == 5 && (word == self.get_answer() || is_valid_guess(word.as_str()))
+    }
+
+    fn answer(&self) -> String {
+        self.get_answer()
+    }
+
+    fn remaining(&self) -> usize {
+        Wordle::remaining(self)
+    }
+
+    fn state(&self) -> String {
+        Wordle::state(self)
+    }
+
+    fn guess(&mut self, input: &[char]) -> Result<Vec<(char, LetterHint)>, GuessError> {
+        let input: [char; 5] = input.try_into().map_err(|_| {GuessError::NotInWordList})?;
+        Wordle::guess(self, input).map(|hints| {hints.to_vec()})
+    }
+}
+
+/// Reads a line written by `Puzzle::state`: `wordle` with its scrambled answer, `absurd` with the
+/// words still possible, or `nerdle` with its equation.
+pub fn restore_puzzle(state: &str) -> Option<Box<dyn Puzzle>> {
+    match state.split_whitespace().collect::<Vec<_>>().as_slice() {
+        ["nerdle", equation] if is_valid_equation(equation) => Some(Box::new(Nerdle::new(equation))),
+        _ => Wordle::from_state(state).map(|wordle| {Box::new(wordle) as Box<dyn Puzzle>}),
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use crate::session::GameStatus;
+use crate::wordle::LetterHint;
+
+/// What a player sends to the relay. Guesses travel as hint patterns only, never as letters.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ClientMessage {
+    Join { room: String, name: String },
+    Guess(Vec<LetterHint>),
+    Finish(GameStatus),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct RaceResult {
+    pub name: String,
+    pub won: bool,
+    pub guesses: usize,
+    pub millis: u64,
+}
+
+/// What the relay sends back: the shared seed on joining, then everybody's progress.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ServerMessage {
+    Seed(u64),
+    Joined(String),
+    Left(String),
+    Progress { name: String, hints: Vec<LetterHint> },
+    Finished(RaceResult),
+    /// Sent once every player in the room has finished; `None` when nobody solved it.
+    Winner(Option<RaceResult>),
+}
+
+/// Fewest guesses wins, ties go to the faster player.
+pub fn race_winner(results: &[RaceResult]) -> Option<&RaceResult> {
+    results.iter()
+        .filter(|result| {result.won})
+        .min_by_key(|result| {(result.guesses, result.millis)})
+}
+
+/// Room and player names travel as single words.
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.len() <= 24 && name.chars().all(|c| {c.is_ascii_alphanumeric() || c == '-' || c == '_'})
+}
+
+fn pattern(hints: &[LetterHint]) -> String {
+    hints.iter().map(|hint| {hint.symbol()}).collect()
+}
+
+fn parse_pattern(s: &str) -> Result<Vec<LetterHint>, ()> {
+    s.chars().map(|c| {LetterHint::from_symbol(c).ok_or(())}).collect()
+}
+
+fn parse_status(s: &str) -> Result<GameStatus, ()> {
+    match s {
+        "won" => Ok(GameStatus::Won),
+        "lost" => Ok(GameStatus::Lost),
+        _ => Err(()),
+    }
+}
+
+fn parse_name(s: &str) -> Result<String, ()> {
+    if is_valid_name(s) { Ok(s.to_string()) } else { Err(()) }
+}
+
+impl fmt::Display for ClientMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Join { room, name } => write!(f, "join {} {}", room, name),
+            Self::Guess(hints) => write!(f, "guess {}", pattern(hints)),
+            Self::Finish(status) => write!(f, "finish {}", if *status == GameStatus::Won { "won" } else { "lost" }),
+        }
+    }
+}
+
+impl FromStr for ClientMessage {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_whitespace().collect::<Vec<_>>().as_slice() {
+            ["join", room, name] => Ok(Self::Join { room: parse_name(room)?, name: parse_name(name)? }),
+            ["guess", hints] => Ok(Self::Guess(parse_pattern(hints)?)),
+            ["finish", status] => Ok(Self::Finish(parse_status(status)?)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for RaceResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.name, if self.won { "won" } else { "lost" }, self.guesses, self.millis)
+    }
+}
+
+impl FromStr for RaceResult {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_whitespace().collect::<Vec<_>>().as_slice() {
+            [name, status, guesses, millis] => Ok(Self {
+                name: parse_name(name)?,
+                won: parse_status(status)? == GameStatus::Won,
+                guesses: guesses.parse().map_err(|_| ())?,
+                millis: millis.parse().map_err(|_| ())?,
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for ServerMessage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Seed(seed) => write!(f, "seed {}", seed),
+            Self::Joined(name) => write!(f, "joined {}", name),
+            Self::Left(name) => write!(f, "left {}", name),
+            Self::Progress { name, hints } => write!(f, "progress {} {}", name, pattern(hints)),
+            Self::Finished(result) => write!(f, "finished {}", result),
+            Self::Winner(Some(result)) => write!(f, "winner {}", result),
+            Self::Winner(None) => write!(f, "winner"),
+        }
+    }
+}
+
+impl FromStr for ServerMessage {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, rest) = s.split_once(' ').unwrap_or((s, ""));
+        match (kind, rest.split_whitespace().collect::<Vec<_>>().as_slice()) {
+            ("seed", [seed]) => Ok(Self::Seed(seed.parse().map_err(|_| ())?)),
+            ("joined", [name]) => Ok(Self::Joined(parse_name(name)?)),
+            ("left", [name]) => Ok(Self::Left(parse_name(name)?)),
+            ("progress", [name, hints]) => Ok(Self::Progress { name: parse_name(name)?, hints: parse_pattern(hints)? }),
+            ("finished", _) => Ok(Self::Finished(rest.parse()?)),
+            ("winner", []) => Ok(Self::Winner(None)),
+            ("winner", _) => Ok(Self::Winner(Some(rest.parse()?))),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordle::LetterHint::{Absent, Correct, Present};
+
+    fn result(name: &str, won: bool, guesses: usize, millis: u64) -> RaceResult {
+        RaceResult { name: name.to_string(), won, guesses, millis }
+    }
+
+    #[test]
+    fn test_messages_round_trip() {
+        let client = [
+            ClientMessage::Join { room: "team".to_string(), name: "ann".to_string() },
+            ClientMessage::Guess(vec![Correct, Present, Absent, Absent, Correct]),
+            ClientMessage::Finish(GameStatus::Lost),
+        ];
+        for message in client {
+            assert_eq!(Ok(message.clone()), message.to_string().parse());
+        }
+        assert_eq!("guess gy..g", ClientMessage::Guess(vec![Correct, Present, Absent, Absent, Correct]).to_string());
+
+        let server = [
+            ServerMessage::Seed(42),
+            ServerMessage::Joined("bob".to_string()),
+            ServerMessage::Progress { name: "bob".to_string(), hints: vec![Absent; 5] },
+            ServerMessage::Finished(result("bob", true, 3, 41_200)),
+            ServerMessage::Winner(Some(result("bob", true, 3, 41_200))),
+            ServerMessage::Winner(None),
+        ];
+        for message in server {
+            assert_eq!(Ok(message.clone()), message.to_string().parse());
+        }
+        assert_eq!(Err(()), "join team two words".parse::<ClientMessage>());
+        assert_eq!(Err(()), "join te@m ann".parse::<ClientMessage>());
+    }
+
+    #[test]
+    fn test_fewest_guesses_then_time_wins() {
+        let results = [
+            result("ann", true, 4, 30_000),
+            result("bob", true, 3, 50_000),
+            result("cat", true, 3, 45_000),
+            result("dan", false, 6, 10_000),
+        ];
+        assert_eq!("cat", race_winner(&results).unwrap().name);
+        assert_eq!(None, race_winner(&results[3..]));
+    }
+}
@@ -0,0 +1,359 @@
+use crate::buffer::GuessBuffer;
+use crate::hint::{find_hint, Hint, HintKind};
+use crate::key::Key;
+use crate::nerdle::Nerdle;
+use crate::puzzle::Puzzle;
+use crate::wordle::{today_puzzle_number, KeyStates, LetterHint, Wordle};
+
+pub const MAX_GUESSES: usize = 6;
+pub const ADVERSARIAL_MAX_GUESSES: usize = 8;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum GameStatus {
+    InProgress,
+    Won,
+    Lost,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum GuessError {
+    GameOver,
+    NotEnoughLetters,
+    NotInWordList,
+}
+
+pub struct GameSession {
+    puzzle: Box<dyn Puzzle>,
+    guesses: Vec<Vec<(char, LetterHint)>>,
+    current_guess: GuessBuffer,
+    hints: Vec<Hint>,
+    max_guesses: usize,
+    status: GameStatus,
+}
+
+impl GameSession {
+    pub fn new(puzzle: impl Puzzle + 'static) -> Self {
+        Self {
+            guesses: vec![],
+            current_guess: GuessBuffer::new(puzzle.width()),
+            hints: vec![],
+            max_guesses: puzzle.max_guesses(),
+            puzzle: Box::new(puzzle),
+            status: GameStatus::InProgress,
+        }
+    }
+
+    /// Picks a game up again from its puzzle and the rows and hints played so far, as long as they fit.
+    pub fn restore(
+        puzzle: Box<dyn Puzzle>,
+        guesses: Vec<Vec<(char, LetterHint)>>,
+        hints: Vec<Hint>,
+        max_guesses: usize,
+    ) -> Option<Self> {
+        let width = puzzle.width();
+        if guesses.len() > max_guesses || guesses.iter().any(|row| {row.len() != width}) {
+            return None;
+        }
+        let won = guesses.last().is_some_and(|row| {row.iter().all(|&(_, hint)| {hint == LetterHint::Correct})});
+        let solved_early = guesses.iter().rev().skip(1).any(|row| {row.iter().all(|&(_, hint)| {hint == LetterHint::Correct})});
+        if solved_early {
+            return None;
+        }
+        Some(Self {
+            status: if won {
+                GameStatus::Won
+            } else if guesses.len() == max_guesses {
+                GameStatus::Lost
+            } else {
+                GameStatus::InProgress
+            },
+            current_guess: GuessBuffer::new(width),
+            puzzle,
+            guesses,
+            hints,
+            max_guesses,
+        })
+    }
+
+    /// The puzzle as `restore_puzzle` reads it back.
+    pub fn puzzle_state(&self) -> String {
+        self.puzzle.state()
+    }
+
+    pub fn with_max_guesses(puzzle: impl Puzzle + 'static, max_guesses: usize) -> Self {
+        Self {
+            max_guesses,
+            ..Self::new(puzzle)
+        }
+    }
+
+    pub fn of_the_day() -> Self {
+        Self::new(Wordle::new_of_the_day())
+    }
+
+    pub fn adversarial() -> Self {
+        Self::new(Wordle::new_adversarial())
+    }
+
+    pub fn equation_of_the_day() -> Self {
+        Self::new(Nerdle::new_for_puzzle(today_puzzle_number()))
+    }
+
+    pub fn width(&self) -> usize {
+        self.puzzle.width()
+    }
+
+    pub fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+
+    pub fn remaining_answers(&self) -> usize {
+        self.puzzle.remaining()
+    }
+
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    /// The answer, which stays hidden until the game is over.
+    pub fn answer(&self) -> Option<String> {
+        match self.status {
+            GameStatus::InProgress => None,
+            _ => Some(self.puzzle.answer()),
+        }
+    }
+
+    pub fn guesses(&self) -> &[Vec<(char, LetterHint)>] {
+        self.guesses.as_slice()
+    }
+
+    pub fn current_guess(&self) -> &GuessBuffer {
+        &self.current_guess
+    }
+
+    pub fn hints(&self) -> &[Hint] {
+        self.hints.as_slice()
+    }
+
+    /// Hints need a settled answer, so adversarial puzzles never offer them.
+    pub fn can_hint(&self) -> bool {
+        self.status == GameStatus::InProgress && self.remaining_answers() == 1
+    }
+
+    pub fn hint(&mut self, kind: HintKind) -> Option<Hint> {
+        if !self.can_hint() {
+            return None;
+        }
+        let solved = (0..self.width())
+            .map(|i| {
+                self.guesses.iter().any(|row| {row.get(i).is_some_and(|&(_, h)| {h == LetterHint::Correct})})
+                    || self.hints.iter().any(|hint| {matches!(hint, Hint::Letter(j, _) if *j == i)})
+            })
+            .collect::<Vec<_>>();
+        let hint = find_hint(kind, &self.puzzle.answer(), &solved, &self.key_states(), |c| {self.accepts(c)})?;
+        self.hints.push(hint.clone());
+        Some(hint)
+    }
+
+    pub fn accepts(&self, c: char) -> bool {
+        self.puzzle.accepts(c)
+    }
+
+    pub fn validate(&self, guess: &[char]) -> bool {
+        self.puzzle.validate(guess)
+    }
+
+    pub fn key_states(&self) -> KeyStates {
+        self.key_states_revealed(self.width())
+    }
+
+    pub fn key_states_revealed(&self, revealed_tiles: usize) -> KeyStates {
+        let last_row = self.guesses.len().saturating_sub(1);
+        let hinted = self.hints.iter()
+            .flat_map(Hint::key_hints)
+            .fold(KeyStates::default(), |states, (c, hint)| {states.with(c, hint)});
+        self.guesses.iter().enumerate()
+            .fold(hinted, |states, (i, row)| {
+                row.iter()
+                    .take(if i == last_row { revealed_tiles } else { row.len() })
+                    .fold(states, |states, &(c, hint)| {states.with(c, hint)})
+            })
+    }
+
+    pub fn press(&mut self, key: Key) -> Result<bool, GuessError> {
+        match key {
+            Key::Letter(c) => Ok(self.push_letter(c)),
+            Key::Backspace => Ok(self.pop_letter()),
+            Key::Enter => self.submit().map(|_| {true}),
+            Key::Left => Ok(self.status == GameStatus::InProgress && self.current_guess.move_left()),
+            Key::Right => Ok(self.status == GameStatus::InProgress && self.current_guess.move_right()),
+        }
+    }
+
+    pub fn push_letter(&mut self, c: char) -> bool {
+        self.status == GameStatus::InProgress && self.accepts(c) && self.current_guess.insert(c)
+    }
+
+    pub fn pop_letter(&mut self) -> bool {
+        self.status == GameStatus::InProgress && self.current_guess.delete()
+    }
+
+    pub fn move_cursor(&mut self, i: usize) -> bool {
+        self.status == GameStatus::InProgress && self.current_guess.move_to(i)
+    }
+
+    pub fn submit(&mut self) -> Result<Vec<(char, LetterHint)>, GuessError> {
+        if self.status != GameStatus::InProgress {
+            return Err(GuessError::GameOver);
+        }
+        if !self.current_guess.is_full() {
+            return Err(GuessError::NotEnoughLetters);
+        }
+        let guess = self.current_guess.to_vec();
+        let hints = self.guess(guess.as_slice())?;
+        self.current_guess.clear();
+        Ok(hints)
+    }
+
+    pub fn guess(&mut self, guess: &[char]) -> Result<Vec<(char, LetterHint)>, GuessError> {
+        if self.status != GameStatus::InProgress {
+            return Err(GuessError::GameOver);
+        }
+        let hints = self.puzzle.guess(guess)?;
+        self.guesses.push(hints.clone());
+        if hints.iter().all(|(_, h)| {*h == LetterHint::Correct}) {
+            self.status = GameStatus::Won;
+        } else if self.guesses.len() == self.max_guesses {
+            self.status = GameStatus::Lost;
+        }
+        Ok(hints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordle::LetterHint::{Absent, Correct, Present};
+
+    fn cigar() -> GameSession {
+        GameSession::new(Wordle::new(['c', 'i', 'g', 'a', 'r']))
+    }
+
+    fn type_word(session: &mut GameSession, word: &str) {
+        word.chars().for_each(|c| {
+            session.press(Key::Letter(c)).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_input_is_limited_to_five_letters() {
+        let mut session = cigar();
+        type_word(&mut session, "crane");
+        assert!(!session.push_letter('x'));
+        assert_eq!(vec!['c', 'r', 'a', 'n', 'e'], session.current_guess().to_vec());
+        assert!(session.pop_letter());
+        assert_eq!(vec!['c', 'r', 'a', 'n'], session.current_guess().to_vec());
+    }
+
+    #[test]
+    fn test_submit_rejects_short_and_unknown_words() {
+        let mut session = cigar();
+        type_word(&mut session, "cra");
+        assert_eq!(Err(GuessError::NotEnoughLetters), session.submit());
+        type_word(&mut session, "xx");
+        assert_eq!(Err(GuessError::NotInWordList), session.submit());
+        assert!(session.guesses().is_empty());
+        assert_eq!(5, session.current_guess().len());
+    }
+
+    #[test]
+    fn test_editing_a_tile_in_the_middle() {
+        let mut session = cigar();
+        type_word(&mut session, "cxgar");
+        assert!(session.move_cursor(1));
+        assert_eq!(Ok(true), session.press(Key::Backspace));
+        assert_eq!(Err(GuessError::NotEnoughLetters), session.submit());
+        type_word(&mut session, "i");
+        session.press(Key::Left).unwrap();
+        session.press(Key::Right).unwrap();
+        assert_eq!(Ok(true), session.press(Key::Enter));
+        assert_eq!(GameStatus::Won, session.status());
+    }
+
+    #[test]
+    fn test_win() {
+        let mut session = cigar();
+        type_word(&mut session, "cigar");
+        assert_eq!(Ok(true), session.press(Key::Enter));
+        assert_eq!(GameStatus::Won, session.status());
+        assert!(!session.push_letter('a'));
+        assert_eq!(Err(GuessError::GameOver), session.submit());
+    }
+
+    #[test]
+    fn test_loss_after_max_guesses() {
+        let mut session = cigar();
+        for _ in 0..MAX_GUESSES {
+            assert_eq!(GameStatus::InProgress, session.status());
+            type_word(&mut session, "crane");
+            session.submit().unwrap();
+        }
+        assert_eq!(GameStatus::Lost, session.status());
+        assert_eq!(Some("cigar".to_string()), session.answer());
+    }
+
+    #[test]
+    fn test_adversarial_session_allows_more_guesses() {
+        let mut session = GameSession::adversarial();
+        for _ in 0..MAX_GUESSES {
+            type_word(&mut session, "fuzzy");
+            session.submit().unwrap();
+        }
+        assert_eq!(GameStatus::InProgress, session.status());
+        assert_eq!(ADVERSARIAL_MAX_GUESSES, session.max_guesses());
+    }
+
+    #[test]
+    fn test_session_only_accepts_its_alphabet() {
+        let mut session = cigar();
+        assert!(!session.push_letter('7'));
+        let mut equations = GameSession::new(Nerdle::new("12+35=47"));
+        assert!(!equations.push_letter('a'));
+        "12+35=4".chars().for_each(|c| {assert!(equations.push_letter(c))});
+        assert_eq!(Err(GuessError::NotEnoughLetters), equations.submit());
+        equations.push_letter('7');
+        assert!(equations.submit().is_ok());
+        assert_eq!(GameStatus::Won, equations.status());
+    }
+
+    #[test]
+    fn test_hints() {
+        let mut session = cigar();
+        type_word(&mut session, "crane");
+        session.submit().unwrap();
+        assert_eq!(Some(Hint::Letter(1, 'i')), session.hint(HintKind::Letter));
+        assert_eq!(Some(Hint::Letter(2, 'g')), session.hint(HintKind::Letter));
+        assert_eq!(Some(Hint::Eliminate(vec!['b', 'd', 'f'])), session.hint(HintKind::Eliminate));
+        assert_eq!(Some(Correct), session.key_states().get('g'));
+        assert_eq!(Some(Absent), session.key_states().get('d'));
+        assert_eq!(3, session.hints().len());
+
+        let mut adversarial = GameSession::adversarial();
+        assert!(!adversarial.can_hint());
+        assert_eq!(None, adversarial.hint(HintKind::Letter));
+    }
+
+    #[test]
+    fn test_key_states_follow_revealed_tiles() {
+        let mut session = cigar();
+        type_word(&mut session, "crane");
+        session.submit().unwrap();
+        assert_eq!(Some(Correct), session.key_states().get('c'));
+        assert_eq!(Some(Present), session.key_states().get('r'));
+        assert_eq!(Some(Absent), session.key_states().get('e'));
+
+        let partial = session.key_states_revealed(2);
+        assert_eq!(Some(Present), partial.get('r'));
+        assert_eq!(None, partial.get('a'));
+    }
+}
@@ -0,0 +1,58 @@
+use crate::multi::MultiSession;
+use crate::session::GameStatus;
+use crate::timer::format_duration;
+
+/// Builds the spoiler-free text players paste elsewhere: a score line, marked when hints were used,
+/// followed by one emoji grid per board.
+pub fn share_text(title: &str, session: &MultiSession, elapsed: Option<u64>) -> String {
+    let score = match session.status() {
+        GameStatus::Won => session.turns().to_string(),
+        _ => "X".to_string(),
+    };
+    let mut text = format!("YDW {} {}/{}", title, score, session.max_guesses());
+    if session.hints_used() > 0 {
+        text += &format!(" 💡{}", session.hints_used());
+    }
+    if let Some(elapsed) = elapsed {
+        text += &format!(" ⏱ {}", format_duration(elapsed));
+    }
+    for board in session.boards() {
+        text.push('\n');
+        for row in board.guesses() {
+            text.push('\n');
+            text.extend(row.iter().map(|(_, hint)| {hint.emoji()}));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hint::HintKind;
+    use crate::key::Key;
+    use crate::session::GameSession;
+    use crate::wordle::Wordle;
+
+    #[test]
+    fn test_share_text_includes_time() {
+        let mut session = MultiSession::single(GameSession::new(Wordle::new(['c', 'i', 'g', 'a', 'r'])));
+        for word in ["crane", "cigar"] {
+            word.chars().for_each(|c| {session.press(Key::Letter(c)).unwrap();});
+            session.submit().unwrap();
+        }
+        assert_eq!(
+            "YDW #0 2/6 ⏱ 0:42.0\n\n🟩🟨🟨⬛⬛\n🟩🟩🟩🟩🟩",
+            share_text("#0", &session, Some(42_000)),
+        );
+    }
+
+    #[test]
+    fn test_share_text_marks_hints() {
+        let mut session = MultiSession::single(GameSession::new(Wordle::new(['c', 'i', 'g', 'a', 'r'])));
+        session.hint(HintKind::Letter);
+        "cigar".chars().for_each(|c| {session.press(Key::Letter(c)).unwrap();});
+        session.submit().unwrap();
+        assert_eq!("YDW #0 1/6 💡1\n\n🟩🟩🟩🟩🟩", share_text("#0", &session, None));
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+use crate::wordle::{answers, score, LetterHint};
+
+pub trait Strategy: Sync {
+    fn name(&self) -> &str;
+
+    fn next_guess(&self, turn: usize, candidates: &[&'static str]) -> [char; 5];
+}
+
+pub struct FirstCandidate;
+
+impl Strategy for FirstCandidate {
+    fn name(&self) -> &str {
+        "first"
+    }
+
+    fn next_guess(&self, _turn: usize, candidates: &[&'static str]) -> [char; 5] {
+        to_chars(candidates[0])
+    }
+}
+
+pub struct MaxPartitions {
+    opener: [char; 5],
+}
+
+impl MaxPartitions {
+    pub fn new(opener: Option<[char; 5]>) -> Self {
+        Self {
+            opener: opener.unwrap_or_else(|| {best_partition(answers())}),
+        }
+    }
+}
+
+impl Strategy for MaxPartitions {
+    fn name(&self) -> &str {
+        "partition"
+    }
+
+    fn next_guess(&self, turn: usize, candidates: &[&'static str]) -> [char; 5] {
+        match turn {
+            0 => self.opener,
+            _ => best_partition(candidates),
+        }
+    }
+}
+
+fn to_chars(word: &str) -> [char; 5] {
+    let mut chars = [' '; 5];
+    chars.iter_mut().zip(word.chars()).for_each(|(slot, c)| {*slot = c});
+    chars
+}
+
+fn best_partition(candidates: &[&'static str]) -> [char; 5] {
+    let best = candidates.iter()
+        .max_by_key(|guess| {
+            let guess = to_chars(guess);
+            candidates.iter()
+                .map(|answer| {score(answer, guess)})
+                .collect::<HashSet<[LetterHint; 5]>>()
+                .len()
+        })
+        .unwrap_or(&candidates[0]);
+    to_chars(best)
+}
+
+pub struct Solver {
+    candidates: Vec<&'static str>,
+    turn: usize,
+}
+
+impl Solver {
+    pub fn new() -> Self {
+        Self {
+            candidates: answers().to_vec(),
+            turn: 0,
+        }
+    }
+
+    pub fn candidates(&self) -> &[&'static str] {
+        self.candidates.as_slice()
+    }
+
+    pub fn apply(&mut self, guess: [char; 5], hints: [LetterHint; 5]) {
+        self.candidates.retain(|answer| {score(answer, guess) == hints});
+        self.turn += 1;
+    }
+
+    pub fn next_guess(&self, strategy: &dyn Strategy) -> Option<[char; 5]> {
+        match self.candidates.as_slice() {
+            [] => None,
+            candidates => Some(strategy.next_guess(self.turn, candidates)),
+        }
+    }
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn solve(answer: &str, strategy: &dyn Strategy, max_turns: usize) -> Vec<[(char, LetterHint); 5]> {
+    let mut solver = Solver::new();
+    let mut path = vec![];
+    while path.len() < max_turns {
+        let guess = match solver.next_guess(strategy) {
+            Some(guess) => guess,
+            None => break,
+        };
+        let hints = score(answer, guess);
+        path.push([0, 1, 2, 3, 4].map(|i| {(guess[i], hints[i])}));
+        if hints.iter().all(|h| {*h == LetterHint::Correct}) {
+            break;
+        }
+        solver.apply(guess, hints);
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wordle::LetterHint::{Absent, Correct, Present};
+
+    #[test]
+    fn test_apply_keeps_consistent_candidates() {
+        let mut solver = Solver::new();
+        solver.apply(to_chars("crane"), [Correct, Present, Present, Absent, Absent]);
+        assert!(solver.candidates().contains(&"cigar"));
+        assert!(solver.candidates().iter().all(|w| {w.starts_with('c') && !w.contains('n')}));
+    }
+
+    #[test]
+    fn test_solve_ends_on_answer() {
+        let strategy = MaxPartitions::new(parse("salet"));
+        let path = solve("cigar", &strategy, 10);
+        assert_eq!(['s', 'a', 'l', 'e', 't'], path[0].map(|(c, _)| {c}));
+        assert!(path.len() <= 6);
+        assert!(path.last().unwrap().iter().all(|(_, h)| {*h == Correct}));
+    }
+
+    #[test]
+    fn test_first_candidate_strategy() {
+        let path = solve("rebut", &FirstCandidate, 10);
+        assert_eq!(['a', 'b', 'a', 'c', 'k'], path[0].map(|(c, _)| {c}));
+        assert_eq!("rebut", path.last().unwrap().iter().map(|(c, _)| {c}).collect::<String>());
+    }
+
+    fn parse(word: &str) -> Option<[char; 5]> {
+        crate::wordle::parse_word(word)
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use crate::session::{GameStatus, MAX_GUESSES};
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Stats {
+    pub played: u32,
+    pub wins: u32,
+    pub current_streak: u32,
+    pub max_streak: u32,
+    pub distribution: [u32; MAX_GUESSES],
+    /// Games finished with the help of at least one hint.
+    pub hinted: u32,
+}
+
+impl Stats {
+    pub fn record(&mut self, status: GameStatus, guesses: usize, hints: usize) {
+        match status {
+            GameStatus::InProgress => return,
+            GameStatus::Won => {
+                self.wins += 1;
+                self.current_streak += 1;
+                self.max_streak = self.max_streak.max(self.current_streak);
+                if let Some(count) = guesses.checked_sub(1).and_then(|i| {self.distribution.get_mut(i)}) {
+                    *count += 1;
+                }
+            },
+            GameStatus::Lost => self.current_streak = 0,
+        }
+        if hints > 0 {
+            self.hinted += 1;
+        }
+        self.played += 1;
+    }
+
+    pub fn win_rate(&self) -> u32 {
+        match self.played {
+            0 => 0,
+            played => self.wins * 100 / played,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.played, self.wins, self.current_streak, self.max_streak)?;
+        self.distribution.iter().try_for_each(|count| {write!(f, " {}", count)})?;
+        write!(f, " {}", self.hinted)
+    }
+}
+
+impl FromStr for Stats {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = s.split_whitespace()
+            .map(|n| {n.parse::<u32>().map_err(|_| ())})
+            .collect::<Result<Vec<u32>, ()>>()?;
+        match numbers.as_slice() {
+            [played, wins, current_streak, max_streak, rest @ ..] => {
+                // Files written before hints existed stop after the distribution.
+                let (distribution, hinted) = match rest {
+                    [distribution @ .., hinted] if rest.len() == MAX_GUESSES + 1 => (distribution, *hinted),
+                    distribution => (distribution, 0),
+                };
+                Ok(Self {
+                    played: *played,
+                    wins: *wins,
+                    current_streak: *current_streak,
+                    max_streak: *max_streak,
+                    distribution: distribution.try_into().map_err(|_| ())?,
+                    hinted,
+                })
+            },
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_streaks() {
+        let mut stats = Stats::default();
+        stats.record(GameStatus::Won, 3, 0);
+        stats.record(GameStatus::Won, 4, 2);
+        stats.record(GameStatus::Lost, 6, 0);
+        stats.record(GameStatus::Won, 3, 0);
+        assert_eq!(4, stats.played);
+        assert_eq!(3, stats.wins);
+        assert_eq!(1, stats.current_streak);
+        assert_eq!(2, stats.max_streak);
+        assert_eq!([0, 0, 2, 1, 0, 0], stats.distribution);
+        assert_eq!(75, stats.win_rate());
+        assert_eq!(1, stats.hinted);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut stats = Stats::default();
+        stats.record(GameStatus::Won, 2, 1);
+        assert_eq!("1 1 1 1 0 1 0 0 0 0 1", stats.to_string());
+        assert_eq!(Ok(stats), stats.to_string().parse());
+        assert_eq!(Ok(Stats { hinted: 0, ..stats }), "1 1 1 1 0 1 0 0 0 0".parse());
+        assert_eq!(Err(()), "1 1 1".parse::<Stats>());
+    }
+}
@@ -0,0 +1,133 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use chrono::Utc;
+
+/// A source of milliseconds, so timed games can be driven by a fake clock in tests.
+pub trait Clock {
+    fn now(&self) -> u64;
+}
+
+#[derive(Copy, Clone, Default, Debug)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        Utc::now().timestamp_millis() as u64
+    }
+}
+
+/// A clock that only moves when told to. Clones share the same time.
+#[derive(Clone, Default, Debug)]
+pub struct ManualClock(Rc<Cell<u64>>);
+
+impl ManualClock {
+    pub fn advance(&self, millis: u64) {
+        self.0.set(self.0.get() + millis);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> u64 {
+        self.0.get()
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct Stopwatch<C: Clock = SystemClock> {
+    clock: C,
+    started: Option<u64>,
+    stopped: Option<u64>,
+    splits: Vec<u64>,
+}
+
+impl<C: Clock> Stopwatch<C> {
+    pub fn new(clock: C) -> Self {
+        Self {
+            clock,
+            started: None,
+            stopped: None,
+            splits: vec![],
+        }
+    }
+
+    /// Starts the clock unless it has already been started. Returns whether it just started.
+    pub fn start(&mut self) -> bool {
+        if self.started.is_some() {
+            return false;
+        }
+        self.started = Some(self.clock.now());
+        true
+    }
+
+    pub fn stop(&mut self) {
+        if self.is_running() {
+            self.stopped = Some(self.clock.now());
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.started.is_some() && self.stopped.is_none()
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.is_some()
+    }
+
+    pub fn elapsed(&self) -> u64 {
+        match self.started {
+            Some(started) => self.stopped.unwrap_or_else(|| {self.clock.now()}) - started,
+            None => 0,
+        }
+    }
+
+    /// Records the time taken since the previous split and returns it.
+    pub fn split(&mut self) -> u64 {
+        let split = self.elapsed() - self.splits.iter().sum::<u64>();
+        if self.is_running() {
+            self.splits.push(split);
+        }
+        split
+    }
+
+    pub fn splits(&self) -> &[u64] {
+        self.splits.as_slice()
+    }
+}
+
+/// Formats milliseconds as `m:ss.d`.
+pub fn format_duration(millis: u64) -> String {
+    let tenths = millis / 100;
+    format!("{}:{:02}.{}", tenths / 600, tenths / 10 % 60, tenths % 10)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stopwatch_starts_once_and_records_splits() {
+        let clock = ManualClock::default();
+        let mut stopwatch = Stopwatch::new(clock.clone());
+        clock.advance(5000);
+        assert_eq!(0, stopwatch.elapsed());
+        assert!(stopwatch.start());
+        clock.advance(1200);
+        assert!(!stopwatch.start());
+        assert_eq!(1200, stopwatch.split());
+        clock.advance(3000);
+        assert_eq!(3000, stopwatch.split());
+        stopwatch.stop();
+        clock.advance(10_000);
+        assert_eq!(4200, stopwatch.elapsed());
+        assert_eq!([1200, 3000], stopwatch.splits());
+        assert!(!stopwatch.is_running());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("0:00.0", format_duration(0));
+        assert_eq!("0:09.9", format_duration(9_999));
+        assert_eq!("1:23.4", format_duration(83_400));
+        assert_eq!("61:00.0", format_duration(3_660_000));
+    }
+}
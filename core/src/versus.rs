@@ -0,0 +1,247 @@
+use crate::buffer::GuessBuffer;
+use crate::key::Key;
+use crate::multi::MultiSession;
+use crate::session::{GameSession, GameStatus, GuessError, MAX_GUESSES};
+use crate::wordle::{is_letter, is_valid_guess, LetterHint, Wordle};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum VersusPhase {
+    /// The player is picking the word their opponent has to find.
+    Choosing(usize),
+    Playing(usize),
+}
+
+/// Two players pick a word for each other, then take turns guessing. As with blitz, the board
+/// being played belongs to the caller; the one waiting for its turn is parked here.
+pub struct Versus {
+    entry: GuessBuffer,
+    words: Vec<[char; 5]>,
+    parked: Option<MultiSession>,
+    turn: usize,
+    /// Whether both players share this device, rather than one of them answering a link.
+    hot_seat: bool,
+}
+
+impl Versus {
+    pub fn new() -> Self {
+        Self {
+            entry: GuessBuffer::new(5),
+            words: vec![],
+            parked: None,
+            turn: 0,
+            hot_seat: true,
+        }
+    }
+
+    /// Joins through a link carrying the first player's word: the second player picks one back, then plays.
+    pub fn answering(word: [char; 5]) -> Self {
+        Self {
+            words: vec![word],
+            turn: 1,
+            hot_seat: false,
+            ..Self::new()
+        }
+    }
+
+    pub fn phase(&self) -> VersusPhase {
+        match self.words.len() {
+            n if n < 2 => VersusPhase::Choosing(n),
+            _ => VersusPhase::Playing(self.turn),
+        }
+    }
+
+    pub fn is_hot_seat(&self) -> bool {
+        self.hot_seat
+    }
+
+    /// The word being typed while choosing.
+    pub fn entry(&self) -> &GuessBuffer {
+        &self.entry
+    }
+
+    /// The words picked so far, in player order; a player's word is the one their opponent guesses.
+    pub fn words(&self) -> &[[char; 5]] {
+        self.words.as_slice()
+    }
+
+    pub fn parked(&self) -> Option<&MultiSession> {
+        self.parked.as_ref()
+    }
+
+    /// Types into the word being chosen. Once both words are in, returns the board to play first.
+    pub fn choose(&mut self, key: Key) -> Result<Option<MultiSession>, GuessError> {
+        if !matches!(self.phase(), VersusPhase::Choosing(_)) {
+            return Err(GuessError::GameOver);
+        }
+        match key {
+            Key::Letter(c) if is_letter(c) => self.entry.insert(c),
+            Key::Letter(_) => false,
+            Key::Backspace => self.entry.delete(),
+            Key::Left => self.entry.move_left(),
+            Key::Right => self.entry.move_right(),
+            Key::Enter => return self.submit(),
+        };
+        Ok(None)
+    }
+
+    pub fn move_cursor(&mut self, i: usize) -> bool {
+        matches!(self.phase(), VersusPhase::Choosing(_)) && self.entry.move_to(i)
+    }
+
+    fn submit(&mut self) -> Result<Option<MultiSession>, GuessError> {
+        if !self.entry.is_full() {
+            return Err(GuessError::NotEnoughLetters);
+        }
+        let word = self.entry.to_vec();
+        if !is_valid_guess(&word.iter().collect::<String>()) {
+            return Err(GuessError::NotInWordList);
+        }
+        self.words.push([word[0], word[1], word[2], word[3], word[4]]);
+        self.entry.clear();
+        if self.words.len() < 2 {
+            return Ok(None);
+        }
+        if self.hot_seat {
+            self.parked = Some(self.board_for(1));
+        }
+        Ok(Some(self.board_for(self.turn)))
+    }
+
+    /// Each player guesses the word the other one picked.
+    fn board_for(&self, player: usize) -> MultiSession {
+        MultiSession::single(GameSession::new(Wordle::new(self.words[1 - player])))
+    }
+
+    /// Hands the device to the other player if they are still guessing, swapping `session` for their board.
+    pub fn take_turn(&mut self, session: &mut MultiSession) -> bool {
+        match &mut self.parked {
+            Some(parked) if parked.status() == GameStatus::InProgress => {
+                std::mem::swap(parked, session);
+                self.turn = 1 - self.turn;
+                true
+            },
+            _ => false,
+        }
+    }
+}
+
+impl Default for Versus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The player who found their word in fewer guesses, or `None` for a draw.
+pub fn versus_winner(results: [(GameStatus, usize); 2]) -> Option<usize> {
+    let score = |(status, guesses): (GameStatus, usize)| {
+        if status == GameStatus::Won { guesses } else { usize::MAX }
+    };
+    let (first, second) = (score(results[0]), score(results[1]));
+    match first.cmp(&second) {
+        std::cmp::Ordering::Less => Some(0),
+        std::cmp::Ordering::Greater => Some(1),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+/// Writes a board as its hint patterns, e.g. `gy..g-ggggg`, so a reply link shows how the other
+/// player did without giving away their guesses.
+pub fn encode_result(rows: &[Vec<LetterHint>]) -> String {
+    rows.iter()
+        .map(|row| {row.iter().map(|hint| {hint.symbol()}).collect::<String>()})
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+pub fn decode_result(code: &str) -> Option<Vec<Vec<LetterHint>>> {
+    let rows = code.split('-')
+        .map(|row| {row.chars().map(LetterHint::from_symbol).collect::<Option<Vec<_>>>()})
+        .collect::<Option<Vec<_>>>()?;
+    let solved = |row: &Vec<LetterHint>| {row.iter().all(|&hint| {hint == LetterHint::Correct})};
+    let valid = rows.len() <= MAX_GUESSES
+        && rows.iter().all(|row| {row.len() == 5})
+        && !rows[..rows.len() - 1].iter().any(solved);
+    if valid { Some(rows) } else { None }
+}
+
+/// How a board decoded from a reply link ended.
+pub fn result_status(rows: &[Vec<LetterHint>]) -> GameStatus {
+    match rows.last() {
+        Some(row) if row.iter().all(|&hint| {hint == LetterHint::Correct}) => GameStatus::Won,
+        _ if rows.len() >= MAX_GUESSES => GameStatus::Lost,
+        _ => GameStatus::InProgress,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_word(versus: &mut Versus, word: &str) -> Result<Option<MultiSession>, GuessError> {
+        for c in word.chars() {
+            versus.choose(Key::Letter(c))?;
+        }
+        versus.choose(Key::Enter)
+    }
+
+    fn guess(session: &mut MultiSession, word: &str) {
+        word.chars().for_each(|c| {session.push_letter(c);});
+        session.submit().unwrap();
+    }
+
+    #[test]
+    fn test_hot_seat() {
+        let mut versus = Versus::new();
+        assert_eq!(VersusPhase::Choosing(0), versus.phase());
+        assert_eq!(Err(GuessError::NotEnoughLetters), type_word(&mut versus, "cig").map(|_| ()));
+        versus.choose(Key::Backspace).unwrap();
+        versus.choose(Key::Backspace).unwrap();
+        versus.choose(Key::Backspace).unwrap();
+        assert_eq!(Err(GuessError::NotInWordList), type_word(&mut versus, "zzzzz").map(|_| ()));
+        (0..5).for_each(|_| {versus.choose(Key::Backspace).unwrap();});
+        assert!(type_word(&mut versus, "cigar").unwrap().is_none());
+        assert_eq!(VersusPhase::Choosing(1), versus.phase());
+
+        let mut session = type_word(&mut versus, "crane").unwrap().unwrap();
+        assert_eq!(VersusPhase::Playing(0), versus.phase());
+        assert!(session.answers().is_empty());
+
+        guess(&mut session, "slate");
+        assert!(versus.take_turn(&mut session));
+        assert_eq!(VersusPhase::Playing(1), versus.phase());
+        guess(&mut session, "cigar");
+        assert_eq!(vec!["cigar"], session.answers());
+        assert!(versus.take_turn(&mut session));
+        guess(&mut session, "crane");
+        assert_eq!(vec!["crane"], session.answers());
+        assert!(!versus.take_turn(&mut session));
+
+        let results = [(session.status(), session.turns()), (versus.parked().unwrap().status(), versus.parked().unwrap().turns())];
+        assert_eq!(Some(1), versus_winner(results));
+    }
+
+    #[test]
+    fn test_answering_a_link() {
+        let mut versus = Versus::answering(['c', 'i', 'g', 'a', 'r']);
+        assert_eq!(VersusPhase::Choosing(1), versus.phase());
+        let mut session = type_word(&mut versus, "crane").unwrap().unwrap();
+        guess(&mut session, "cigar");
+        assert_eq!(GameStatus::Won, session.status());
+        assert!(!versus.take_turn(&mut session));
+    }
+
+    #[test]
+    fn test_results() {
+        use LetterHint::{Absent, Correct, Present};
+        let rows = vec![vec![Correct, Present, Absent, Absent, Correct], vec![Correct; 5]];
+        assert_eq!("gy..g-ggggg", encode_result(&rows));
+        assert_eq!(Some(rows.clone()), decode_result("gy..g-ggggg"));
+        assert_eq!(GameStatus::Won, result_status(&rows));
+        assert_eq!(None, decode_result("ggggg-ggggg"));
+        assert_eq!(None, decode_result("gy..g-gggg"));
+        assert_eq!(None, decode_result("....?"));
+        assert_eq!(Some(0), versus_winner([(GameStatus::Won, 3), (GameStatus::Won, 4)]));
+        assert_eq!(Some(0), versus_winner([(GameStatus::Won, 6), (GameStatus::Lost, 6)]));
+        assert_eq!(None, versus_winner([(GameStatus::Lost, 6), (GameStatus::Lost, 6)]));
+    }
+}
@@ -1,2407 +1,2622 @@
-use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::ops::Sub;
-use std::convert::TryInto;
-use chrono::{Local, TimeZone};
+use std::sync::OnceLock;
+use chrono::{Local, NaiveDate};
+use crate::cipher::{scramble, unscramble};
+use crate::session::GuessError;
 use crate::wordle::LetterHint::{Absent, Correct, Present};
 
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum LetterHint {
     Correct,
     Present,
     Absent,
 }
 
-pub type GuessResult = Result<[(char, LetterHint); 5], ()>;
+impl LetterHint {
+    pub fn symbol(&self) -> char {
+        match self {
+            Correct => 'g',
+            Present => 'y',
+            Absent => '.',
+        }
+    }
+
+    pub fn from_symbol(c: char) -> Option<Self> {
+        match c {
+            'g' | 'G' => Some(Correct),
+            'y' | 'Y' => Some(Present),
+            '.' | 'b' | 'B' | 'x' | 'X' => Some(Absent),
+            _ => None,
+        }
+    }
+
+    pub fn emoji(&self) -> char {
+        match self {
+            Correct => '🟩',
+            Present => '🟨',
+            Absent => '⬛',
+        }
+    }
+}
+
+pub type GuessResult = Result<[(char, LetterHint); 5], GuessError>;
+
+const SYMBOLS: &str = "+-*/=";
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct KeyStates([Option<LetterHint>; 41]);
+
+impl Default for KeyStates {
+    fn default() -> Self {
+        Self([None; 41])
+    }
+}
+
+impl KeyStates {
+    pub fn from_hints<'a>(hints: impl IntoIterator<Item = &'a (char, LetterHint)>) -> Self {
+        hints.into_iter()
+            .fold(Self::default(), |states, &(c, hint)| {states.with(c, hint)})
+    }
+
+    pub fn get(&self, c: char) -> Option<LetterHint> {
+        Self::index(c).and_then(|i| {self.0[i]})
+    }
+
+    pub fn with(mut self, c: char, hint: LetterHint) -> Self {
+        if let Some(i) = Self::index(c) {
+            self.0[i] = match (self.0[i], hint) {
+                (Some(Correct), _) => Some(Correct),
+                (Some(Present), Absent) => Some(Present),
+                (_, hint) => Some(hint),
+            };
+        }
+        self
+    }
+
+    fn index(c: char) -> Option<usize> {
+        match c {
+            'a' ..= 'z' => Some(c as usize - 'a' as usize),
+            '0' ..= '9' => Some(26 + c as usize - '0' as usize),
+            _ => SYMBOLS.find(c).map(|i| {36 + i}),
+        }
+    }
+}
+
+/// Answers are kept scrambled, both in the word list and inside a game, so that neither the binary
+/// nor the game state spells them out. Each letter is rotated by the key's amount for its position.
+const ANSWER_KEY: [u8; 5] = [7, 19, 2, 13, 23];
+/// Seeds the shuffle that decides which answer belongs to which day.
+const SCHEDULE_KEY: u64 = 0x7977_645f_6461_7973;
+
+/// An answer as it is stored, only turned back into a word to score a guess or to end the game.
+#[derive(Clone, PartialEq, Debug)]
+struct Secret(String);
+
+impl Secret {
+    fn hide(word: &str) -> Self {
+        Self(scramble(word, &ANSWER_KEY))
+    }
+
+    fn reveal(&self) -> String {
+        unscramble(&self.0, &ANSWER_KEY)
+    }
+}
+
+enum Answer {
+    Fixed(Secret),
+    Adversarial(Vec<&'static str>),
+}
 
 pub struct Wordle {
-    answer: String,
+    answer: Answer,
 }
 
 impl Wordle {
     pub fn new(word: [char; 5]) -> Self {
         Self {
-            answer: word.iter().collect()
+            answer: Answer::Fixed(Secret::hide(&word.iter().collect::<String>()))
         }
     }
 
     pub fn new_of_the_day() -> Self {
+        Self::new_for_puzzle(today_puzzle_number())
+    }
+
+    pub fn new_for_date(date: NaiveDate) -> Self {
+        Self::new_for_puzzle(puzzle_number(date))
+    }
+
+    pub fn new_for_puzzle(number: i64) -> Self {
+        Self {
+            answer: Answer::Fixed(secret_for_puzzle(number))
+        }
+    }
+
+    pub fn new_practice(seed: u64) -> Self {
+        let hidden = HIDDEN_ANSWERS[(seed % HIDDEN_ANSWERS.len() as u64) as usize];
         Self {
-            answer: word_of_the_day().into()
+            answer: Answer::Fixed(Secret(hidden.to_string()))
+        }
+    }
+
+    pub fn new_adversarial() -> Self {
+        Self {
+            answer: Answer::Adversarial(answers().to_vec())
+        }
+    }
+
+    pub fn is_adversarial(&self) -> bool {
+        matches!(self.answer, Answer::Adversarial(_))
+    }
+
+    pub fn remaining(&self) -> usize {
+        match &self.answer {
+            Answer::Fixed(_) => 1,
+            Answer::Adversarial(candidates) => candidates.len(),
+        }
+    }
+
+    /// The puzzle as `restore_puzzle` reads it back. A fixed answer stays scrambled.
+    pub fn state(&self) -> String {
+        match &self.answer {
+            Answer::Fixed(secret) => format!("wordle {}", secret.0),
+            Answer::Adversarial(candidates) => format!("absurd {}", candidates.join(" ")),
         }
     }
 
-    pub fn get_answer(&self) -> &str {
-        self.answer.as_str()
+    pub(crate) fn from_state(state: &str) -> Option<Self> {
+        let answer = match state.split_whitespace().collect::<Vec<_>>().as_slice() {
+            ["wordle", secret] if secret.len() == 5 && secret.chars().all(is_letter) => Answer::Fixed(Secret(secret.to_string())),
+            ["absurd", candidates @ ..] if !candidates.is_empty() => Answer::Adversarial(
+                candidates.iter()
+                    .map(|word| {answers().binary_search(word).ok().map(|i| {answers()[i]})})
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            _ => return None,
+        };
+        Some(Self { answer })
     }
 
-    pub fn guess(&self, input: [char; 5]) -> GuessResult {
+    pub fn get_answer(&self) -> String {
+        match &self.answer {
+            Answer::Fixed(secret) => secret.reveal(),
+            Answer::Adversarial(candidates) => candidates[0].to_string(),
+        }
+    }
+
+    pub fn guess(&mut self, input: [char; 5]) -> GuessResult {
         let input_word: String = input.iter().collect();
-        if input_word == self.answer {
-            Ok(input.map(|c| {(c, Correct)}))
-        } else {
-            let input_str = input_word.as_str();
-            if ANSWERS.contains(&input_str) || ALLOWED_GUESSES.contains(&input_str) {
-                let mut map = self.answer.chars().enumerate()
-                    .fold(HashMap::<char, (usize, Vec<usize>)>::new(), |mut m, (i, c)| {
-                        m.entry(c).and_modify(|(count, indexes)| {
-                            indexes.push(i);
-                            *count = indexes.len();
-                        }).or_insert_with(|| {
-                            let indexes = vec![i];
-                            (indexes.len(), indexes)
-                        });
-                        m
-                    });
-                let result = input_word.chars().enumerate()
-                    .map(|(index, c)| {
-                        match map.entry(c) {
-                            Entry::Vacant(_) => Absent,
-                            Entry::Occupied(mut e) => {
-                                match e.get_mut() {
-                                    (0, _) => Absent,
-                                    (count, indexes) => {
-                                       *count -=1;
-                                        if indexes.contains(&index) {
-                                            Correct
-                                        } else {
-                                            Present
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }).collect::<Vec<LetterHint>>().as_slice().try_into().unwrap();
-                Ok(input.zip(result))
-            } else {
-                Err(())
-            }
+        let result = match &mut self.answer {
+            Answer::Fixed(secret) if Secret::hide(&input_word) == *secret => [Correct; 5],
+            _ if !is_valid_guess(input_word.as_str()) => return Err(GuessError::NotInWordList),
+            Answer::Fixed(secret) => score(&secret.reveal(), input),
+            Answer::Adversarial(candidates) => narrow(candidates, input),
+        };
+        Ok([0, 1, 2, 3, 4].map(|i| {(input[i], result[i])}))
+    }
+}
+
+fn narrow(candidates: &mut Vec<&'static str>, input: [char; 5]) -> [LetterHint; 5] {
+    let mut buckets = HashMap::<[LetterHint; 5], Vec<&'static str>>::new();
+    candidates.drain(..).for_each(|answer| {
+        buckets.entry(score(answer, input)).or_default().push(answer);
+    });
+    let rank = |hints: &[LetterHint; 5]| {(
+        hints.iter().filter(|h| {**h == Correct}).count(),
+        hints.iter().filter(|h| {**h == Present}).count(),
+        hints.map(|h| {h.symbol()}),
+    )};
+    let (hints, bucket) = buckets.into_iter()
+        .max_by(|(a_hints, a), (b_hints, b)| {
+            a.len().cmp(&b.len()).then_with(|| {rank(b_hints).cmp(&rank(a_hints))})
+        })
+        .unwrap();
+    *candidates = bucket;
+    hints
+}
+
+/// Letters in the right place are matched first, so a repeated letter is only present when the answer has
+/// a copy left over after those.
+pub fn score<const N: usize>(answer: &str, input: [char; N]) -> [LetterHint; N] {
+    let answer = answer.chars().collect::<Vec<char>>();
+    let mut unmatched = answer.iter().zip(&input).filter(|(a, c)| {a != c}).map(|(a, _)| {*a}).collect::<Vec<char>>();
+    let mut hints = [Absent; N];
+    for (index, c) in input.iter().enumerate() {
+        if answer.get(index) == Some(c) {
+            hints[index] = Correct;
+        } else if let Some(found) = unmatched.iter().position(|a| {a == c}) {
+            unmatched.swap_remove(found);
+            hints[index] = Present;
         }
     }
+    hints
+}
+
+pub fn parse_word(word: &str) -> Option<[char; 5]> {
+    let chars = word.chars().map(|c| {c.to_ascii_lowercase()}).collect::<Vec<char>>();
+    if chars.iter().all(char::is_ascii_lowercase) {
+        chars.try_into().ok()
+    } else {
+        None
+    }
+}
+
+pub fn is_letter(c: char) -> bool {
+    c.is_ascii_lowercase()
+}
+
+pub fn is_valid_guess(word: &str) -> bool {
+    HIDDEN_ANSWERS.contains(&Secret::hide(word).0.as_str()) || ALLOWED_GUESSES.contains(&word)
 }
 
-fn word_of_the_day() -> &'static str {
-    let start = Local.ymd(2022, 1u32, 1u32);
-    let days = Local::today().sub(start).num_days();
-    ANSWERS[days as usize % ANSWERS.len()]
+/// Every answer in plain text, alphabetically. This is for tools like the solver; a game only ever
+/// decodes its own answer.
+pub fn answers() -> &'static [&'static str] {
+    static ANSWERS: OnceLock<Vec<&'static str>> = OnceLock::new();
+    ANSWERS.get_or_init(|| {
+        HIDDEN_ANSWERS.iter()
+            .map(|hidden| {&*Box::leak(Secret(hidden.to_string()).reveal().into_boxed_str())})
+            .collect()
+    })
 }
 
-static ANSWERS: [&str; 2315] = [
-    "cigar",
-    "rebut",
-    "sissy",
-    "humph",
-    "awake",
-    "blush",
-    "focal",
-    "evade",
-    "naval",
-    "serve",
-    "heath",
-    "dwarf",
-    "model",
-    "karma",
-    "stink",
-    "grade",
-    "quiet",
-    "bench",
-    "abate",
-    "feign",
-    "major",
-    "death",
-    "fresh",
-    "crust",
-    "stool",
-    "colon",
-    "abase",
-    "marry",
-    "react",
-    "batty",
-    "pride",
-    "floss",
-    "helix",
-    "croak",
-    "staff",
-    "paper",
-    "unfed",
-    "whelp",
-    "trawl",
-    "outdo",
-    "adobe",
-    "crazy",
-    "sower",
-    "repay",
-    "digit",
-    "crate",
-    "cluck",
-    "spike",
-    "mimic",
-    "pound",
-    "maxim",
-    "linen",
-    "unmet",
-    "flesh",
-    "booby",
-    "forth",
-    "first",
-    "stand",
-    "belly",
-    "ivory",
-    "seedy",
-    "print",
-    "yearn",
-    "drain",
-    "bribe",
-    "stout",
-    "panel",
-    "crass",
-    "flume",
-    "offal",
-    "agree",
-    "error",
-    "swirl",
-    "argue",
-    "bleed",
-    "delta",
-    "flick",
-    "totem",
-    "wooer",
-    "front",
-    "shrub",
-    "parry",
-    "biome",
-    "lapel",
-    "start",
-    "greet",
-    "goner",
-    "golem",
-    "lusty",
-    "loopy",
-    "round",
-    "audit",
-    "lying",
-    "gamma",
-    "labor",
-    "islet",
-    "civic",
-    "forge",
-    "corny",
-    "moult",
-    "basic",
-    "salad",
-    "agate",
-    "spicy",
-    "spray",
-    "essay",
-    "fjord",
-    "spend",
-    "kebab",
-    "guild",
-    "aback",
-    "motor",
-    "alone",
-    "hatch",
-    "hyper",
-    "thumb",
-    "dowry",
-    "ought",
-    "belch",
-    "dutch",
-    "pilot",
-    "tweed",
-    "comet",
-    "jaunt",
-    "enema",
-    "steed",
-    "abyss",
-    "growl",
-    "fling",
-    "dozen",
-    "boozy",
-    "erode",
-    "world",
-    "gouge",
-    "click",
-    "briar",
-    "great",
-    "altar",
-    "pulpy",
-    "blurt",
-    "coast",
-    "duchy",
-    "groin",
-    "fixer",
-    "group",
-    "rogue",
-    "badly",
-    "smart",
-    "pithy",
-    "gaudy",
-    "chill",
-    "heron",
-    "vodka",
-    "finer",
-    "surer",
-    "radio",
-    "rouge",
-    "perch",
-    "retch",
-    "wrote",
-    "clock",
-    "tilde",
-    "store",
-    "prove",
-    "bring",
-    "solve",
-    "cheat",
-    "grime",
-    "exult",
-    "usher",
-    "epoch",
-    "triad",
-    "break",
-    "rhino",
-    "viral",
-    "conic",
-    "masse",
-    "sonic",
-    "vital",
-    "trace",
-    "using",
-    "peach",
-    "champ",
-    "baton",
-    "brake",
-    "pluck",
-    "craze",
-    "gripe",
-    "weary",
-    "picky",
-    "acute",
-    "ferry",
-    "aside",
-    "tapir",
-    "troll",
-    "unify",
-    "rebus",
-    "boost",
-    "truss",
-    "siege",
-    "tiger",
-    "banal",
-    "slump",
-    "crank",
-    "gorge",
-    "query",
-    "drink",
-    "favor",
-    "abbey",
-    "tangy",
-    "panic",
-    "solar",
-    "shire",
-    "proxy",
-    "point",
-    "robot",
-    "prick",
-    "wince",
-    "crimp",
-    "knoll",
-    "sugar",
-    "whack",
-    "mount",
-    "perky",
-    "could",
-    "wrung",
-    "light",
-    "those",
-    "moist",
-    "shard",
-    "pleat",
-    "aloft",
-    "skill",
-    "elder",
-    "frame",
-    "humor",
-    "pause",
-    "ulcer",
-    "ultra",
-    "robin",
-    "cynic",
-    "agora",
-    "aroma",
-    "caulk",
-    "shake",
-    "pupal",
-    "dodge",
-    "swill",
-    "tacit",
-    "other",
-    "thorn",
-    "trove",
-    "bloke",
-    "vivid",
-    "spill",
-    "chant",
-    "choke",
-    "rupee",
-    "nasty",
-    "mourn",
-    "ahead",
-    "brine",
-    "cloth",
-    "hoard",
-    "sweet",
-    "month",
-    "lapse",
-    "watch",
-    "today",
-    "focus",
-    "smelt",
-    "tease",
-    "cater",
-    "movie",
-    "lynch",
-    "saute",
-    "allow",
-    "renew",
-    "their",
-    "slosh",
-    "purge",
-    "chest",
-    "depot",
-    "epoxy",
-    "nymph",
-    "found",
-    "shall",
-    "harry",
-    "stove",
-    "lowly",
-    "snout",
-    "trope",
-    "fewer",
-    "shawl",
-    "natal",
-    "fibre",
-    "comma",
-    "foray",
-    "scare",
-    "stair",
-    "black",
-    "squad",
-    "royal",
-    "chunk",
-    "mince",
-    "slave",
-    "shame",
-    "cheek",
-    "ample",
-    "flair",
-    "foyer",
-    "cargo",
-    "oxide",
-    "plant",
-    "olive",
-    "inert",
-    "askew",
-    "heist",
-    "shown",
-    "zesty",
-    "hasty",
-    "trash",
-    "fella",
-    "larva",
-    "forgo",
-    "story",
-    "hairy",
-    "train",
-    "homer",
-    "badge",
-    "midst",
-    "canny",
-    "fetus",
-    "butch",
-    "farce",
-    "slung",
-    "tipsy",
-    "metal",
-    "yield",
-    "delve",
-    "being",
-    "scour",
-    "glass",
-    "gamer",
-    "scrap",
-    "money",
-    "hinge",
-    "album",
-    "vouch",
-    "asset",
-    "tiara",
-    "crept",
-    "bayou",
-    "atoll",
-    "manor",
-    "creak",
-    "showy",
-    "phase",
-    "froth",
-    "depth",
-    "gloom",
-    "flood",
-    "trait",
-    "girth",
-    "piety",
-    "payer",
-    "goose",
-    "float",
-    "donor",
-    "atone",
-    "primo",
-    "apron",
-    "blown",
-    "cacao",
-    "loser",
-    "input",
-    "gloat",
-    "awful",
-    "brink",
-    "smite",
-    "beady",
-    "rusty",
-    "retro",
-    "droll",
-    "gawky",
-    "hutch",
-    "pinto",
-    "gaily",
-    "egret",
-    "lilac",
-    "sever",
-    "field",
-    "fluff",
-    "hydro",
-    "flack",
-    "agape",
-    "wench",
-    "voice",
-    "stead",
-    "stalk",
-    "berth",
-    "madam",
-    "night",
-    "bland",
-    "liver",
-    "wedge",
-    "augur",
-    "roomy",
-    "wacky",
-    "flock",
-    "angry",
-    "bobby",
-    "trite",
-    "aphid",
-    "tryst",
-    "midge",
-    "power",
-    "elope",
-    "cinch",
-    "motto",
-    "stomp",
-    "upset",
-    "bluff",
-    "cramp",
-    "quart",
-    "coyly",
-    "youth",
-    "rhyme",
-    "buggy",
-    "alien",
-    "smear",
-    "unfit",
-    "patty",
-    "cling",
-    "glean",
-    "label",
-    "hunky",
-    "khaki",
-    "poker",
-    "gruel",
-    "twice",
-    "twang",
-    "shrug",
-    "treat",
-    "unlit",
-    "waste",
-    "merit",
-    "woven",
-    "octal",
-    "needy",
-    "clown",
-    "widow",
-    "irony",
-    "ruder",
-    "gauze",
-    "chief",
-    "onset",
-    "prize",
-    "fungi",
-    "charm",
-    "gully",
-    "inter",
-    "whoop",
-    "taunt",
-    "leery",
-    "class",
-    "theme",
-    "lofty",
-    "tibia",
-    "booze",
-    "alpha",
-    "thyme",
-    "eclat",
-    "doubt",
-    "parer",
-    "chute",
-    "stick",
-    "trice",
-    "alike",
-    "sooth",
-    "recap",
-    "saint",
-    "liege",
-    "glory",
-    "grate",
-    "admit",
-    "brisk",
-    "soggy",
-    "usurp",
-    "scald",
-    "scorn",
-    "leave",
-    "twine",
-    "sting",
-    "bough",
-    "marsh",
-    "sloth",
-    "dandy",
-    "vigor",
-    "howdy",
-    "enjoy",
-    "valid",
-    "ionic",
-    "equal",
-    "unset",
-    "floor",
-    "catch",
-    "spade",
-    "stein",
-    "exist",
-    "quirk",
-    "denim",
-    "grove",
-    "spiel",
-    "mummy",
-    "fault",
-    "foggy",
-    "flout",
-    "carry",
-    "sneak",
-    "libel",
-    "waltz",
-    "aptly",
-    "piney",
-    "inept",
-    "aloud",
-    "photo",
-    "dream",
-    "stale",
-    "vomit",
-    "ombre",
-    "fanny",
-    "unite",
-    "snarl",
-    "baker",
-    "there",
-    "glyph",
-    "pooch",
-    "hippy",
-    "spell",
-    "folly",
-    "louse",
-    "gulch",
-    "vault",
-    "godly",
-    "threw",
-    "fleet",
-    "grave",
-    "inane",
-    "shock",
-    "crave",
-    "spite",
-    "valve",
-    "skimp",
-    "claim",
-    "rainy",
-    "musty",
-    "pique",
-    "daddy",
-    "quasi",
-    "arise",
-    "aging",
-    "valet",
-    "opium",
-    "avert",
-    "stuck",
-    "recut",
-    "mulch",
-    "genre",
-    "plume",
-    "rifle",
-    "count",
-    "incur",
-    "total",
-    "wrest",
-    "mocha",
-    "deter",
-    "study",
-    "lover",
-    "safer",
-    "rivet",
-    "funny",
-    "smoke",
-    "mound",
-    "undue",
-    "sedan",
-    "pagan",
-    "swine",
-    "guile",
-    "gusty",
-    "equip",
-    "tough",
-    "canoe",
-    "chaos",
-    "covet",
-    "human",
-    "udder",
-    "lunch",
-    "blast",
-    "stray",
-    "manga",
-    "melee",
-    "lefty",
-    "quick",
-    "paste",
-    "given",
-    "octet",
-    "risen",
-    "groan",
-    "leaky",
-    "grind",
-    "carve",
-    "loose",
-    "sadly",
-    "spilt",
-    "apple",
-    "slack",
-    "honey",
-    "final",
-    "sheen",
-    "eerie",
-    "minty",
-    "slick",
-    "derby",
-    "wharf",
-    "spelt",
-    "coach",
-    "erupt",
-    "singe",
-    "price",
-    "spawn",
-    "fairy",
-    "jiffy",
-    "filmy",
-    "stack",
-    "chose",
-    "sleep",
-    "ardor",
-    "nanny",
-    "niece",
-    "woozy",
-    "handy",
-    "grace",
-    "ditto",
-    "stank",
-    "cream",
-    "usual",
-    "diode",
-    "valor",
-    "angle",
-    "ninja",
-    "muddy",
-    "chase",
-    "reply",
-    "prone",
-    "spoil",
-    "heart",
-    "shade",
-    "diner",
-    "arson",
-    "onion",
-    "sleet",
-    "dowel",
-    "couch",
-    "palsy",
-    "bowel",
-    "smile",
-    "evoke",
-    "creek",
-    "lance",
-    "eagle",
-    "idiot",
-    "siren",
-    "built",
-    "embed",
-    "award",
-    "dross",
-    "annul",
-    "goody",
-    "frown",
-    "patio",
-    "laden",
-    "humid",
-    "elite",
-    "lymph",
-    "edify",
-    "might",
-    "reset",
-    "visit",
-    "gusto",
-    "purse",
-    "vapor",
-    "crock",
-    "write",
-    "sunny",
-    "loath",
-    "chaff",
-    "slide",
-    "queer",
-    "venom",
-    "stamp",
-    "sorry",
-    "still",
-    "acorn",
-    "aping",
-    "pushy",
-    "tamer",
-    "hater",
-    "mania",
-    "awoke",
-    "brawn",
-    "swift",
-    "exile",
-    "birch",
-    "lucky",
-    "freer",
-    "risky",
-    "ghost",
-    "plier",
-    "lunar",
-    "winch",
-    "snare",
-    "nurse",
-    "house",
-    "borax",
-    "nicer",
-    "lurch",
-    "exalt",
-    "about",
-    "savvy",
-    "toxin",
-    "tunic",
-    "pried",
-    "inlay",
-    "chump",
-    "lanky",
-    "cress",
-    "eater",
-    "elude",
-    "cycle",
-    "kitty",
-    "boule",
-    "moron",
-    "tenet",
-    "place",
-    "lobby",
-    "plush",
-    "vigil",
-    "index",
-    "blink",
-    "clung",
-    "qualm",
-    "croup",
-    "clink",
-    "juicy",
-    "stage",
-    "decay",
-    "nerve",
-    "flier",
-    "shaft",
-    "crook",
-    "clean",
-    "china",
-    "ridge",
-    "vowel",
-    "gnome",
-    "snuck",
-    "icing",
-    "spiny",
-    "rigor",
-    "snail",
-    "flown",
-    "rabid",
-    "prose",
-    "thank",
-    "poppy",
-    "budge",
-    "fiber",
-    "moldy",
-    "dowdy",
-    "kneel",
-    "track",
-    "caddy",
-    "quell",
-    "dumpy",
-    "paler",
-    "swore",
-    "rebar",
-    "scuba",
-    "splat",
-    "flyer",
-    "horny",
-    "mason",
-    "doing",
-    "ozone",
-    "amply",
-    "molar",
-    "ovary",
-    "beset",
-    "queue",
-    "cliff",
-    "magic",
-    "truce",
-    "sport",
-    "fritz",
-    "edict",
-    "twirl",
-    "verse",
-    "llama",
-    "eaten",
-    "range",
-    "whisk",
-    "hovel",
-    "rehab",
-    "macaw",
-    "sigma",
-    "spout",
-    "verve",
-    "sushi",
-    "dying",
-    "fetid",
-    "brain",
-    "buddy",
-    "thump",
-    "scion",
-    "candy",
-    "chord",
-    "basin",
-    "march",
-    "crowd",
-    "arbor",
-    "gayly",
-    "musky",
-    "stain",
-    "dally",
-    "bless",
-    "bravo",
-    "stung",
-    "title",
-    "ruler",
-    "kiosk",
-    "blond",
-    "ennui",
-    "layer",
-    "fluid",
-    "tatty",
-    "score",
-    "cutie",
-    "zebra",
-    "barge",
-    "matey",
-    "bluer",
-    "aider",
-    "shook",
-    "river",
-    "privy",
-    "betel",
-    "frisk",
-    "bongo",
-    "begun",
-    "azure",
-    "weave",
-    "genie",
-    "sound",
-    "glove",
-    "braid",
-    "scope",
-    "wryly",
-    "rover",
-    "assay",
-    "ocean",
-    "bloom",
-    "irate",
-    "later",
-    "woken",
-    "silky",
-    "wreck",
-    "dwelt",
-    "slate",
-    "smack",
-    "solid",
-    "amaze",
-    "hazel",
-    "wrist",
-    "jolly",
-    "globe",
-    "flint",
-    "rouse",
-    "civil",
-    "vista",
-    "relax",
-    "cover",
-    "alive",
-    "beech",
-    "jetty",
-    "bliss",
-    "vocal",
-    "often",
-    "dolly",
-    "eight",
-    "joker",
-    "since",
-    "event",
-    "ensue",
-    "shunt",
-    "diver",
-    "poser",
-    "worst",
-    "sweep",
-    "alley",
-    "creed",
-    "anime",
-    "leafy",
-    "bosom",
-    "dunce",
-    "stare",
-    "pudgy",
-    "waive",
-    "choir",
-    "stood",
-    "spoke",
-    "outgo",
-    "delay",
-    "bilge",
-    "ideal",
-    "clasp",
-    "seize",
-    "hotly",
-    "laugh",
-    "sieve",
-    "block",
-    "meant",
-    "grape",
-    "noose",
-    "hardy",
-    "shied",
-    "drawl",
-    "daisy",
-    "putty",
-    "strut",
-    "burnt",
-    "tulip",
-    "crick",
-    "idyll",
-    "vixen",
-    "furor",
-    "geeky",
-    "cough",
-    "naive",
-    "shoal",
-    "stork",
-    "bathe",
-    "aunty",
-    "check",
-    "prime",
-    "brass",
-    "outer",
-    "furry",
-    "razor",
-    "elect",
-    "evict",
-    "imply",
-    "demur",
-    "quota",
-    "haven",
-    "cavil",
-    "swear",
-    "crump",
-    "dough",
-    "gavel",
-    "wagon",
-    "salon",
-    "nudge",
-    "harem",
-    "pitch",
-    "sworn",
-    "pupil",
-    "excel",
-    "stony",
-    "cabin",
-    "unzip",
-    "queen",
-    "trout",
-    "polyp",
-    "earth",
-    "storm",
-    "until",
-    "taper",
-    "enter",
-    "child",
-    "adopt",
-    "minor",
-    "fatty",
-    "husky",
-    "brave",
-    "filet",
-    "slime",
-    "glint",
-    "tread",
-    "steal",
-    "regal",
-    "guest",
-    "every",
-    "murky",
-    "share",
-    "spore",
-    "hoist",
-    "buxom",
-    "inner",
-    "otter",
-    "dimly",
-    "level",
-    "sumac",
-    "donut",
-    "stilt",
-    "arena",
-    "sheet",
-    "scrub",
-    "fancy",
-    "slimy",
-    "pearl",
-    "silly",
-    "porch",
-    "dingo",
-    "sepia",
-    "amble",
-    "shady",
-    "bread",
-    "friar",
-    "reign",
-    "dairy",
-    "quill",
-    "cross",
-    "brood",
-    "tuber",
-    "shear",
-    "posit",
-    "blank",
-    "villa",
-    "shank",
-    "piggy",
-    "freak",
-    "which",
-    "among",
-    "fecal",
-    "shell",
-    "would",
-    "algae",
-    "large",
-    "rabbi",
-    "agony",
-    "amuse",
-    "bushy",
-    "copse",
-    "swoon",
-    "knife",
-    "pouch",
-    "ascot",
-    "plane",
-    "crown",
-    "urban",
-    "snide",
-    "relay",
-    "abide",
-    "viola",
-    "rajah",
-    "straw",
-    "dilly",
-    "crash",
-    "amass",
-    "third",
-    "trick",
-    "tutor",
-    "woody",
-    "blurb",
-    "grief",
-    "disco",
-    "where",
-    "sassy",
-    "beach",
-    "sauna",
-    "comic",
-    "clued",
-    "creep",
-    "caste",
-    "graze",
-    "snuff",
-    "frock",
-    "gonad",
-    "drunk",
-    "prong",
-    "lurid",
-    "steel",
-    "halve",
-    "buyer",
-    "vinyl",
-    "utile",
-    "smell",
-    "adage",
-    "worry",
-    "tasty",
-    "local",
-    "trade",
-    "finch",
-    "ashen",
-    "modal",
-    "gaunt",
-    "clove",
-    "enact",
-    "adorn",
-    "roast",
-    "speck",
-    "sheik",
-    "missy",
-    "grunt",
-    "snoop",
-    "party",
-    "touch",
-    "mafia",
-    "emcee",
-    "array",
-    "south",
-    "vapid",
-    "jelly",
-    "skulk",
-    "angst",
-    "tubal",
-    "lower",
-    "crest",
-    "sweat",
-    "cyber",
-    "adore",
-    "tardy",
-    "swami",
-    "notch",
-    "groom",
-    "roach",
-    "hitch",
-    "young",
-    "align",
-    "ready",
-    "frond",
-    "strap",
-    "puree",
-    "realm",
-    "venue",
-    "swarm",
-    "offer",
-    "seven",
-    "dryer",
-    "diary",
-    "dryly",
-    "drank",
-    "acrid",
-    "heady",
-    "theta",
-    "junto",
-    "pixie",
-    "quoth",
-    "bonus",
-    "shalt",
-    "penne",
-    "amend",
-    "datum",
-    "build",
-    "piano",
-    "shelf",
-    "lodge",
-    "suing",
-    "rearm",
-    "coral",
-    "ramen",
-    "worth",
-    "psalm",
-    "infer",
-    "overt",
-    "mayor",
-    "ovoid",
-    "glide",
-    "usage",
-    "poise",
-    "randy",
-    "chuck",
-    "prank",
-    "fishy",
-    "tooth",
-    "ether",
-    "drove",
-    "idler",
-    "swath",
-    "stint",
-    "while",
-    "begat",
-    "apply",
-    "slang",
-    "tarot",
-    "radar",
-    "credo",
-    "aware",
-    "canon",
-    "shift",
-    "timer",
-    "bylaw",
-    "serum",
-    "three",
-    "steak",
-    "iliac",
-    "shirk",
-    "blunt",
-    "puppy",
-    "penal",
-    "joist",
-    "bunny",
-    "shape",
-    "beget",
-    "wheel",
-    "adept",
-    "stunt",
-    "stole",
-    "topaz",
-    "chore",
-    "fluke",
-    "afoot",
-    "bloat",
-    "bully",
-    "dense",
-    "caper",
-    "sneer",
-    "boxer",
-    "jumbo",
-    "lunge",
-    "space",
-    "avail",
-    "short",
-    "slurp",
-    "loyal",
-    "flirt",
-    "pizza",
-    "conch",
-    "tempo",
-    "droop",
-    "plate",
-    "bible",
-    "plunk",
-    "afoul",
-    "savoy",
-    "steep",
-    "agile",
-    "stake",
-    "dwell",
-    "knave",
-    "beard",
-    "arose",
-    "motif",
-    "smash",
-    "broil",
-    "glare",
-    "shove",
-    "baggy",
-    "mammy",
-    "swamp",
-    "along",
-    "rugby",
-    "wager",
-    "quack",
-    "squat",
-    "snaky",
-    "debit",
-    "mange",
-    "skate",
-    "ninth",
-    "joust",
-    "tramp",
-    "spurn",
-    "medal",
-    "micro",
-    "rebel",
-    "flank",
-    "learn",
-    "nadir",
-    "maple",
-    "comfy",
-    "remit",
-    "gruff",
-    "ester",
-    "least",
-    "mogul",
-    "fetch",
-    "cause",
-    "oaken",
-    "aglow",
-    "meaty",
-    "gaffe",
-    "shyly",
-    "racer",
-    "prowl",
-    "thief",
-    "stern",
-    "poesy",
-    "rocky",
-    "tweet",
-    "waist",
-    "spire",
-    "grope",
-    "havoc",
-    "patsy",
-    "truly",
-    "forty",
-    "deity",
-    "uncle",
-    "swish",
-    "giver",
-    "preen",
-    "bevel",
-    "lemur",
-    "draft",
-    "slope",
-    "annoy",
-    "lingo",
-    "bleak",
-    "ditty",
-    "curly",
-    "cedar",
-    "dirge",
-    "grown",
-    "horde",
-    "drool",
-    "shuck",
-    "crypt",
-    "cumin",
-    "stock",
-    "gravy",
-    "locus",
-    "wider",
-    "breed",
-    "quite",
-    "chafe",
-    "cache",
-    "blimp",
-    "deign",
-    "fiend",
-    "logic",
-    "cheap",
-    "elide",
-    "rigid",
-    "false",
-    "renal",
-    "pence",
-    "rowdy",
-    "shoot",
-    "blaze",
-    "envoy",
-    "posse",
-    "brief",
-    "never",
-    "abort",
-    "mouse",
-    "mucky",
-    "sulky",
-    "fiery",
-    "media",
-    "trunk",
-    "yeast",
-    "clear",
-    "skunk",
-    "scalp",
-    "bitty",
-    "cider",
-    "koala",
-    "duvet",
-    "segue",
-    "creme",
-    "super",
-    "grill",
-    "after",
-    "owner",
-    "ember",
-    "reach",
-    "nobly",
-    "empty",
-    "speed",
-    "gipsy",
-    "recur",
-    "smock",
-    "dread",
-    "merge",
-    "burst",
-    "kappa",
-    "amity",
-    "shaky",
-    "hover",
-    "carol",
-    "snort",
-    "synod",
-    "faint",
-    "haunt",
-    "flour",
-    "chair",
-    "detox",
-    "shrew",
-    "tense",
-    "plied",
-    "quark",
-    "burly",
-    "novel",
-    "waxen",
-    "stoic",
-    "jerky",
-    "blitz",
-    "beefy",
-    "lyric",
-    "hussy",
-    "towel",
-    "quilt",
-    "below",
-    "bingo",
-    "wispy",
-    "brash",
-    "scone",
-    "toast",
-    "easel",
-    "saucy",
-    "value",
-    "spice",
-    "honor",
-    "route",
-    "sharp",
-    "bawdy",
-    "radii",
-    "skull",
-    "phony",
-    "issue",
-    "lager",
-    "swell",
-    "urine",
-    "gassy",
-    "trial",
-    "flora",
-    "upper",
-    "latch",
-    "wight",
-    "brick",
-    "retry",
-    "holly",
-    "decal",
-    "grass",
-    "shack",
-    "dogma",
-    "mover",
-    "defer",
-    "sober",
-    "optic",
-    "crier",
-    "vying",
-    "nomad",
-    "flute",
-    "hippo",
-    "shark",
-    "drier",
-    "obese",
-    "bugle",
-    "tawny",
-    "chalk",
-    "feast",
-    "ruddy",
-    "pedal",
-    "scarf",
-    "cruel",
-    "bleat",
-    "tidal",
-    "slush",
-    "semen",
-    "windy",
-    "dusty",
-    "sally",
-    "igloo",
-    "nerdy",
-    "jewel",
-    "shone",
-    "whale",
-    "hymen",
-    "abuse",
-    "fugue",
-    "elbow",
-    "crumb",
-    "pansy",
-    "welsh",
-    "syrup",
-    "terse",
-    "suave",
-    "gamut",
-    "swung",
-    "drake",
-    "freed",
-    "afire",
-    "shirt",
-    "grout",
-    "oddly",
-    "tithe",
-    "plaid",
-    "dummy",
-    "broom",
-    "blind",
-    "torch",
-    "enemy",
-    "again",
-    "tying",
-    "pesky",
-    "alter",
-    "gazer",
-    "noble",
-    "ethos",
-    "bride",
-    "extol",
-    "decor",
-    "hobby",
-    "beast",
-    "idiom",
-    "utter",
-    "these",
-    "sixth",
-    "alarm",
-    "erase",
-    "elegy",
-    "spunk",
-    "piper",
-    "scaly",
-    "scold",
-    "hefty",
-    "chick",
-    "sooty",
-    "canal",
-    "whiny",
-    "slash",
-    "quake",
-    "joint",
-    "swept",
-    "prude",
-    "heavy",
-    "wield",
-    "femme",
-    "lasso",
-    "maize",
-    "shale",
-    "screw",
-    "spree",
-    "smoky",
-    "whiff",
-    "scent",
-    "glade",
-    "spent",
-    "prism",
-    "stoke",
-    "riper",
-    "orbit",
-    "cocoa",
-    "guilt",
-    "humus",
-    "shush",
-    "table",
-    "smirk",
-    "wrong",
-    "noisy",
-    "alert",
-    "shiny",
-    "elate",
-    "resin",
-    "whole",
-    "hunch",
-    "pixel",
-    "polar",
-    "hotel",
-    "sword",
-    "cleat",
-    "mango",
-    "rumba",
-    "puffy",
-    "filly",
-    "billy",
-    "leash",
-    "clout",
-    "dance",
-    "ovate",
-    "facet",
-    "chili",
-    "paint",
-    "liner",
-    "curio",
-    "salty",
-    "audio",
-    "snake",
-    "fable",
-    "cloak",
-    "navel",
-    "spurt",
-    "pesto",
-    "balmy",
-    "flash",
-    "unwed",
-    "early",
-    "churn",
-    "weedy",
-    "stump",
-    "lease",
-    "witty",
-    "wimpy",
-    "spoof",
-    "saner",
-    "blend",
-    "salsa",
-    "thick",
-    "warty",
-    "manic",
-    "blare",
-    "squib",
-    "spoon",
-    "probe",
-    "crepe",
-    "knack",
-    "force",
-    "debut",
-    "order",
-    "haste",
-    "teeth",
-    "agent",
-    "widen",
-    "icily",
-    "slice",
-    "ingot",
-    "clash",
-    "juror",
-    "blood",
-    "abode",
-    "throw",
-    "unity",
-    "pivot",
-    "slept",
-    "troop",
-    "spare",
-    "sewer",
-    "parse",
-    "morph",
-    "cacti",
-    "tacky",
-    "spool",
-    "demon",
-    "moody",
-    "annex",
-    "begin",
-    "fuzzy",
-    "patch",
-    "water",
-    "lumpy",
-    "admin",
-    "omega",
-    "limit",
-    "tabby",
-    "macho",
-    "aisle",
-    "skiff",
-    "basis",
-    "plank",
-    "verge",
-    "botch",
-    "crawl",
-    "lousy",
-    "slain",
-    "cubic",
-    "raise",
-    "wrack",
-    "guide",
-    "foist",
-    "cameo",
-    "under",
-    "actor",
-    "revue",
-    "fraud",
-    "harpy",
-    "scoop",
-    "climb",
-    "refer",
-    "olden",
-    "clerk",
-    "debar",
-    "tally",
-    "ethic",
-    "cairn",
-    "tulle",
-    "ghoul",
-    "hilly",
-    "crude",
-    "apart",
-    "scale",
-    "older",
-    "plain",
-    "sperm",
-    "briny",
-    "abbot",
-    "rerun",
-    "quest",
-    "crisp",
-    "bound",
-    "befit",
-    "drawn",
-    "suite",
-    "itchy",
-    "cheer",
-    "bagel",
-    "guess",
-    "broad",
-    "axiom",
-    "chard",
-    "caput",
-    "leant",
-    "harsh",
-    "curse",
-    "proud",
-    "swing",
-    "opine",
-    "taste",
-    "lupus",
-    "gumbo",
-    "miner",
-    "green",
-    "chasm",
-    "lipid",
-    "topic",
-    "armor",
-    "brush",
-    "crane",
-    "mural",
-    "abled",
-    "habit",
-    "bossy",
-    "maker",
-    "dusky",
-    "dizzy",
-    "lithe",
-    "brook",
-    "jazzy",
-    "fifty",
-    "sense",
-    "giant",
-    "surly",
-    "legal",
-    "fatal",
-    "flunk",
-    "began",
-    "prune",
-    "small",
-    "slant",
-    "scoff",
-    "torus",
-    "ninny",
-    "covey",
-    "viper",
-    "taken",
-    "moral",
-    "vogue",
-    "owing",
-    "token",
-    "entry",
-    "booth",
-    "voter",
-    "chide",
-    "elfin",
-    "ebony",
-    "neigh",
-    "minim",
-    "melon",
-    "kneed",
-    "decoy",
-    "voila",
-    "ankle",
-    "arrow",
-    "mushy",
-    "tribe",
-    "cease",
-    "eager",
-    "birth",
-    "graph",
-    "odder",
-    "terra",
-    "weird",
-    "tried",
-    "clack",
-    "color",
-    "rough",
-    "weigh",
-    "uncut",
-    "ladle",
-    "strip",
-    "craft",
-    "minus",
-    "dicey",
-    "titan",
-    "lucid",
-    "vicar",
-    "dress",
-    "ditch",
-    "gypsy",
-    "pasta",
-    "taffy",
-    "flame",
-    "swoop",
-    "aloof",
-    "sight",
-    "broke",
-    "teary",
-    "chart",
-    "sixty",
-    "wordy",
-    "sheer",
-    "leper",
-    "nosey",
-    "bulge",
-    "savor",
-    "clamp",
-    "funky",
-    "foamy",
-    "toxic",
-    "brand",
-    "plumb",
-    "dingy",
-    "butte",
-    "drill",
-    "tripe",
-    "bicep",
-    "tenor",
-    "krill",
-    "worse",
-    "drama",
-    "hyena",
-    "think",
-    "ratio",
-    "cobra",
-    "basil",
-    "scrum",
-    "bused",
-    "phone",
-    "court",
-    "camel",
-    "proof",
-    "heard",
-    "angel",
-    "petal",
-    "pouty",
-    "throb",
-    "maybe",
-    "fetal",
-    "sprig",
-    "spine",
-    "shout",
-    "cadet",
-    "macro",
-    "dodgy",
-    "satyr",
-    "rarer",
-    "binge",
-    "trend",
-    "nutty",
-    "leapt",
-    "amiss",
-    "split",
-    "myrrh",
-    "width",
-    "sonar",
-    "tower",
-    "baron",
-    "fever",
-    "waver",
-    "spark",
-    "belie",
-    "sloop",
-    "expel",
-    "smote",
-    "baler",
-    "above",
-    "north",
-    "wafer",
-    "scant",
-    "frill",
-    "awash",
-    "snack",
-    "scowl",
-    "frail",
-    "drift",
-    "limbo",
-    "fence",
-    "motel",
-    "ounce",
-    "wreak",
-    "revel",
-    "talon",
-    "prior",
-    "knelt",
-    "cello",
-    "flake",
-    "debug",
-    "anode",
-    "crime",
-    "salve",
-    "scout",
-    "imbue",
-    "pinky",
-    "stave",
-    "vague",
-    "chock",
-    "fight",
-    "video",
-    "stone",
-    "teach",
-    "cleft",
-    "frost",
-    "prawn",
-    "booty",
-    "twist",
-    "apnea",
-    "stiff",
-    "plaza",
-    "ledge",
-    "tweak",
-    "board",
-    "grant",
-    "medic",
-    "bacon",
-    "cable",
-    "brawl",
-    "slunk",
-    "raspy",
-    "forum",
-    "drone",
-    "women",
-    "mucus",
-    "boast",
-    "toddy",
-    "coven",
-    "tumor",
-    "truer",
-    "wrath",
-    "stall",
-    "steam",
-    "axial",
-    "purer",
-    "daily",
-    "trail",
-    "niche",
-    "mealy",
-    "juice",
-    "nylon",
-    "plump",
-    "merry",
-    "flail",
-    "papal",
-    "wheat",
-    "berry",
-    "cower",
-    "erect",
-    "brute",
-    "leggy",
-    "snipe",
-    "sinew",
-    "skier",
-    "penny",
-    "jumpy",
-    "rally",
-    "umbra",
-    "scary",
-    "modem",
-    "gross",
-    "avian",
-    "greed",
-    "satin",
-    "tonic",
-    "parka",
-    "sniff",
-    "livid",
-    "stark",
-    "trump",
-    "giddy",
-    "reuse",
-    "taboo",
-    "avoid",
-    "quote",
-    "devil",
-    "liken",
-    "gloss",
-    "gayer",
-    "beret",
-    "noise",
-    "gland",
-    "dealt",
-    "sling",
-    "rumor",
-    "opera",
-    "thigh",
-    "tonga",
-    "flare",
-    "wound",
-    "white",
-    "bulky",
-    "etude",
-    "horse",
-    "circa",
-    "paddy",
-    "inbox",
-    "fizzy",
-    "grain",
-    "exert",
-    "surge",
-    "gleam",
-    "belle",
-    "salvo",
-    "crush",
-    "fruit",
-    "sappy",
-    "taker",
-    "tract",
-    "ovine",
-    "spiky",
-    "frank",
-    "reedy",
-    "filth",
-    "spasm",
-    "heave",
-    "mambo",
-    "right",
-    "clank",
-    "trust",
-    "lumen",
-    "borne",
-    "spook",
-    "sauce",
-    "amber",
-    "lathe",
-    "carat",
-    "corer",
-    "dirty",
-    "slyly",
-    "affix",
-    "alloy",
-    "taint",
-    "sheep",
-    "kinky",
-    "wooly",
-    "mauve",
-    "flung",
-    "yacht",
-    "fried",
-    "quail",
-    "brunt",
-    "grimy",
-    "curvy",
-    "cagey",
-    "rinse",
-    "deuce",
-    "state",
-    "grasp",
-    "milky",
-    "bison",
-    "graft",
-    "sandy",
-    "baste",
-    "flask",
-    "hedge",
-    "girly",
-    "swash",
-    "boney",
-    "coupe",
-    "endow",
-    "abhor",
-    "welch",
-    "blade",
-    "tight",
-    "geese",
-    "miser",
-    "mirth",
-    "cloud",
-    "cabal",
-    "leech",
-    "close",
-    "tenth",
-    "pecan",
-    "droit",
-    "grail",
-    "clone",
-    "guise",
-    "ralph",
-    "tango",
-    "biddy",
-    "smith",
-    "mower",
-    "payee",
-    "serif",
-    "drape",
-    "fifth",
-    "spank",
-    "glaze",
-    "allot",
-    "truck",
-    "kayak",
-    "virus",
-    "testy",
-    "tepee",
-    "fully",
-    "zonal",
-    "metro",
-    "curry",
-    "grand",
-    "banjo",
-    "axion",
-    "bezel",
-    "occur",
-    "chain",
-    "nasal",
-    "gooey",
-    "filer",
-    "brace",
-    "allay",
-    "pubic",
-    "raven",
-    "plead",
-    "gnash",
-    "flaky",
-    "munch",
-    "dully",
-    "eking",
-    "thing",
-    "slink",
-    "hurry",
-    "theft",
-    "shorn",
-    "pygmy",
-    "ranch",
-    "wring",
-    "lemon",
-    "shore",
-    "mamma",
-    "froze",
-    "newer",
-    "style",
-    "moose",
-    "antic",
-    "drown",
-    "vegan",
-    "chess",
-    "guppy",
-    "union",
-    "lever",
-    "lorry",
-    "image",
-    "cabby",
-    "druid",
-    "exact",
-    "truth",
-    "dopey",
-    "spear",
-    "cried",
-    "chime",
-    "crony",
-    "stunk",
-    "timid",
-    "batch",
-    "gauge",
-    "rotor",
-    "crack",
-    "curve",
-    "latte",
-    "witch",
-    "bunch",
-    "repel",
-    "anvil",
-    "soapy",
-    "meter",
-    "broth",
-    "madly",
-    "dried",
-    "scene",
-    "known",
-    "magma",
-    "roost",
-    "woman",
-    "thong",
-    "punch",
-    "pasty",
-    "downy",
-    "knead",
-    "whirl",
-    "rapid",
-    "clang",
-    "anger",
-    "drive",
-    "goofy",
-    "email",
-    "music",
-    "stuff",
-    "bleep",
-    "rider",
-    "mecca",
-    "folio",
-    "setup",
-    "verso",
-    "quash",
-    "fauna",
-    "gummy",
-    "happy",
-    "newly",
-    "fussy",
-    "relic",
-    "guava",
-    "ratty",
-    "fudge",
-    "femur",
-    "chirp",
-    "forte",
-    "alibi",
-    "whine",
-    "petty",
-    "golly",
-    "plait",
-    "fleck",
-    "felon",
-    "gourd",
-    "brown",
-    "thrum",
-    "ficus",
-    "stash",
-    "decry",
-    "wiser",
-    "junta",
-    "visor",
-    "daunt",
-    "scree",
-    "impel",
-    "await",
-    "press",
-    "whose",
-    "turbo",
-    "stoop",
-    "speak",
-    "mangy",
-    "eying",
-    "inlet",
-    "crone",
-    "pulse",
-    "mossy",
-    "staid",
-    "hence",
-    "pinch",
-    "teddy",
-    "sully",
-    "snore",
-    "ripen",
-    "snowy",
-    "attic",
-    "going",
-    "leach",
-    "mouth",
-    "hound",
-    "clump",
-    "tonal",
-    "bigot",
-    "peril",
-    "piece",
-    "blame",
-    "haute",
-    "spied",
-    "undid",
-    "intro",
-    "basal",
-    "shine",
-    "gecko",
-    "rodeo",
-    "guard",
-    "steer",
-    "loamy",
-    "scamp",
-    "scram",
-    "manly",
-    "hello",
-    "vaunt",
-    "organ",
-    "feral",
-    "knock",
-    "extra",
-    "condo",
-    "adapt",
-    "willy",
-    "polka",
-    "rayon",
-    "skirt",
-    "faith",
-    "torso",
-    "match",
-    "mercy",
-    "tepid",
-    "sleek",
-    "riser",
-    "twixt",
-    "peace",
-    "flush",
-    "catty",
-    "login",
-    "eject",
-    "roger",
-    "rival",
-    "untie",
-    "refit",
-    "aorta",
-    "adult",
-    "judge",
-    "rower",
-    "artsy",
-    "rural",
-    "shave"
+pub fn puzzle_number(date: NaiveDate) -> i64 {
+    date.sub(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap()).num_days()
+}
+
+pub fn word_for_puzzle(number: i64) -> String {
+    secret_for_puzzle(number).reveal()
+}
+
+fn secret_for_puzzle(number: i64) -> Secret {
+    let day = number.rem_euclid(HIDDEN_ANSWERS.len() as i64) as usize;
+    Secret(HIDDEN_ANSWERS[schedule()[day]].to_string())
+}
+
+/// The answer for each day: a shuffle seeded by `SCHEDULE_KEY`, starting over once every answer has
+/// had its day. The list itself is alphabetical, so its order gives nothing away.
+fn schedule() -> &'static [usize] {
+    static SCHEDULE: OnceLock<Vec<usize>> = OnceLock::new();
+    SCHEDULE.get_or_init(|| {
+        let mut state = SCHEDULE_KEY;
+        let mut order = (0..HIDDEN_ANSWERS.len()).collect::<Vec<_>>();
+        for i in (1..order.len()).rev() {
+            order.swap(i, (next_random(&mut state) % (i as u64 + 1)) as usize);
+        }
+        order
+    })
+}
+
+/// SplitMix64, which is plenty for shuffling and the same on every platform.
+fn next_random(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+pub fn today_puzzle_number() -> i64 {
+    puzzle_number(Local::now().naive_local().date())
+}
+
+static HIDDEN_ANSWERS: [&str; 2315] = [
+    "hucph",
+    "hucfb",
+    "hucgb",
+    "hudrv",
+    "hudbq",
+    "hujbo",
+    "hukqb",
+    "hunra",
+    "huqqb",
+    "huqeq",
+    "huqhq",
+    "huqib",
+    "huwfb",
+    "huafp",
+    "hvqek",
+    "hvtva",
+    "hvvbo",
+    "hvwgb",
+    "hwctb",
+    "hwccq",
+    "hwgcq",
+    "hwovk",
+    "hwovq",
+    "hwqob",
+    "hwqcq",
+    "hwqeb",
+    "hwqek",
+    "hwwyq",
+    "hyhvu",
+    "hykeb",
+    "hyqbq",
+    "hyqhi",
+    "hyvro",
+    "hzcvk",
+    "hzccb",
+    "hzcgb",
+    "hzgaq",
+    "hzkyb",
+    "hzkad",
+    "hznbt",
+    "hzqav",
+    "hzqex",
+    "hztrb",
+    "hagna",
+    "hbfro",
+    "hbuyb",
+    "hecej",
+    "hedhj",
+    "hegeq",
+    "heinb",
+    "hekof",
+    "hekrk",
+    "hektk",
+    "hekxb",
+    "hekib",
+    "hennv",
+    "henrv",
+    "henbq",
+    "henbt",
+    "henbv",
+    "heqsq",
+    "heqab",
+    "heqad",
+    "heqbc",
+    "heqha",
+    "herux",
+    "hevno",
+    "hevro",
+    "hfcfp",
+    "hfcmb",
+    "hfdro",
+    "hfdyb",
+    "hfgaa",
+    "hfkfp",
+    "hfkgv",
+    "hfqad",
+    "hfryb",
+    "hfryv",
+    "hfwfb",
+    "hgiri",
+    "hgiro",
+    "hgiyb",
+    "hgiev",
+    "hgifq",
+    "hgkzb",
+    "hgmyb",
+    "hgpru",
+    "hgpbv",
+    "hgphi",
+    "hgqqb",
+    "hgvvz",
+    "hgxvi",
+    "hhtgx",
+    "hiceq",
+    "hijva",
+    "hikad",
+    "hiprx",
+    "hiryb",
+    "hiryv",
+    "hitbk",
+    "hivyv",
+    "hkdbo",
+    "hkfbo",
+    "hkgax",
+    "hkihb",
+    "hkkfb",
+    "hkobo",
+    "hkqzx",
+    "hkqfb",
+    "hktnv",
+    "hktbt",
+    "hkubk",
+    "hkvfv",
+    "hlebq",
+    "hljrk",
+    "hlkqb",
+    "hlmrt",
+    "hlunv",
+    "hlurq",
+    "hmqyi",
+    "hmqab",
+    "hmvvz",
+    "hnfvl",
+    "hnfvq",
+    "hniho",
+    "hnpgv",
+    "hocvi",
+    "hogeq",
+    "hoknk",
+    "hoqva",
+    "hpcvq",
+    "hpcxb",
+    "hpcea",
+    "hpceb",
+    "hpcfe",
+    "hphhi",
+    "hpqxb",
+    "hqkni",
+    "hqkbj",
+    "hqkbk",
+    "hsweb",
+    "itebk",
+    "itftb",
+    "itfyv",
+    "itiri",
+    "ititv",
+    "itmro",
+    "itnro",
+    "itnzv",
+    "itpni",
+    "itpwl",
+    "itttb",
+    "ittbk",
+    "ituni",
+    "ituvz",
+    "ituvi",
+    "ituvk",
+    "ituvp",
+    "itugb",
+    "itvpe",
+    "itvub",
+    "itvbk",
+    "itvgv",
+    "ityqv",
+    "itabr",
+    "ixcpe",
+    "ixcqv",
+    "ixcea",
+    "ixcfq",
+    "ixgpe",
+    "ixgsv",
+    "ixhvq",
+    "ixink",
+    "ixinq",
+    "ixirq",
+    "ixivk",
+    "ixihk",
+    "ixkad",
+    "ixnpe",
+    "ixnvb",
+    "ixnyb",
+    "ixnyv",
+    "ixnbt",
+    "ixppe",
+    "ixtrq",
+    "ixtev",
+    "ixtge",
+    "ixurq",
+    "ixvri",
+    "ixxri",
+    "ixbri",
+    "ibdyb",
+    "iberm",
+    "ibfqv",
+    "ibibq",
+    "ibntb",
+    "ibnyv",
+    "ibptb",
+    "ibptl",
+    "ibqzb",
+    "ibtpe",
+    "ibtge",
+    "ibubk",
+    "ibvgv",
+    "iecph",
+    "iecqb",
+    "ieczb",
+    "iecaa",
+    "iecah",
+    "ieceb",
+    "iecfq",
+    "iecmb",
+    "iegnh",
+    "iegnq",
+    "iegra",
+    "iegrm",
+    "iegaa",
+    "iegfp",
+    "iekzm",
+    "iekaa",
+    "iekah",
+    "iekfp",
+    "iekgw",
+    "ieqnq",
+    "ieqph",
+    "ieqxb",
+    "ieqaa",
+    "ieqba",
+    "ieqbj",
+    "ieqjk",
+    "iewro",
+    "iewsc",
+    "iewaq",
+    "iewey",
+    "ieweq",
+    "iewfe",
+    "ihcea",
+    "ihcfq",
+    "ihdov",
+    "ihprv",
+    "ihptl",
+    "ihphp",
+    "ihqov",
+    "ihqfq",
+    "ihqge",
+    "ihqgv",
+    "ihqmb",
+    "ihqmv",
+    "ihtnu",
+    "ihtab",
+    "ihubj",
+    "ihufv",
+    "ihvpe",
+    "ihwte",
+    "ihwyb",
+    "ihwaa",
+    "ihyri",
+    "ihzro",
+    "ikcpb",
+    "ikcva",
+    "ikcvk",
+    "ikcxb",
+    "ikcaa",
+    "ikcfe",
+    "ikcfp",
+    "ikcib",
+    "ikcil",
+    "ikcji",
+    "ikcjk",
+    "ikgna",
+    "ikgnh",
+    "ikgra",
+    "ikkno",
+    "ikkob",
+    "ikkph",
+    "ikkqb",
+    "ikkrc",
+    "ikkab",
+    "ikkad",
+    "ikkah",
+    "ikkav",
+    "ikkfh",
+    "ikqna",
+    "ikqvi",
+    "ikqxb",
+    "ikqba",
+    "ikqbh",
+    "ikqbj",
+    "ikqge",
+    "ikqjk",
+    "ikwaq",
+    "ikwfe",
+    "ikwgb",
+    "infqv",
+    "inftb",
+    "initv",
+    "iniyb",
+    "inkya",
+    "inkyq",
+    "inntb",
+    "innxv",
+    "innyv",
+    "inppe",
+    "inpav",
+    "intyv",
+    "intaq",
+    "intfq",
+    "inura",
+    "inuuv",
+    "invpe",
+    "invgb",
+    "inzbj",
+    "inaro",
+    "irnnt",
+    "jtdni",
+    "jtdov",
+    "jtdvk",
+    "jtdyb",
+    "jtenl",
+    "jteub",
+    "jtegf",
+    "jtfqv",
+    "jtfrq",
+    "jtirv",
+    "jtkek",
+    "jtori",
+    "jtorl",
+    "jtpni",
+    "jtpqv",
+    "jtpav",
+    "jtpbb",
+    "jtpbk",
+    "jtrro",
+    "jtrhq",
+    "jttnq",
+    "jtttl",
+    "jttbi",
+    "jttev",
+    "jttib",
+    "jtugb",
+    "jtvpe",
+    "jtvro",
+    "jtvgv",
+    "jtwyh",
+    "jtwfb",
+    "jtxvi",
+    "jxcfb",
+    "jxfno",
+    "jxnyl",
+    "jacsb",
+    "jacsc",
+    "jacvk",
+    "jacvo",
+    "jacyh",
+    "jaczm",
+    "jacaq",
+    "jacbp",
+    "jacea",
+    "jacej",
+    "jaceq",
+    "jacfb",
+    "jacfj",
+    "jagnm",
+    "jagnq",
+    "jagph",
+    "jagrh",
+    "jagro",
+    "jagfp",
+    "jagfq",
+    "jakph",
+    "jakqb",
+    "jakrc",
+    "jakya",
+    "jakyf",
+    "jakyi",
+    "jakzb",
+    "jakax",
+    "jakem",
+    "jaqph",
+    "jaqvo",
+    "jaqxb",
+    "jaqea",
+    "jaqeb",
+    "jaqfb",
+    "jawph",
+    "jawzm",
+    "jawah",
+    "jawek",
+    "jawgb",
+    "jbfro",
+    "jbino",
+    "jbppe",
+    "jbtpx",
+    "jbxvz",
+    "jbxvi",
+    "jecph",
+    "jecvj",
+    "jeczm",
+    "jecad",
+    "jecah",
+    "jecfe",
+    "jecfm",
+    "jecfp",
+    "jegnk",
+    "jegno",
+    "jegnq",
+    "jegsq",
+    "jegeh",
+    "jekph",
+    "jeksc",
+    "jekzy",
+    "jekad",
+    "jekah",
+    "jeqnh",
+    "jeqph",
+    "jeqab",
+    "jeqfb",
+    "jeqge",
+    "jeqha",
+    "jeqhq",
+    "jeqib",
+    "jeqjk",
+    "jewph",
+    "jewra",
+    "jewzm",
+    "jewad",
+    "jhcpe",
+    "jhcfq",
+    "jhdex",
+    "jhebx",
+    "jhnbk",
+    "jhnbo",
+    "jhorq",
+    "jhosv",
+    "jhovz",
+    "jhozx",
+    "jhppe",
+    "jhpql",
+    "jhpvz",
+    "jhrfb",
+    "jhtni",
+    "jhtro",
+    "jhtav",
+    "jhwpe",
+    "jhwte",
+    "jhwya",
+    "jhwaq",
+    "jhwcb",
+    "jhweq",
+    "jhxrk",
+    "jhxro",
+    "jhxrq",
+    "jhxrv",
+    "jhyro",
+    "jhayv",
+    "jkcph",
+    "jkcsq",
+    "jkczm",
+    "jkcab",
+    "jkcah",
+    "jkcfe",
+    "jkcfp",
+    "jkcgb",
+    "jkcib",
+    "jkcji",
+    "jkcmb",
+    "jkcmv",
+    "jkgnh",
+    "jkgnj",
+    "jkgql",
+    "jkgra",
+    "jkgrh",
+    "jkgrm",
+    "jkgzb",
+    "jkgcb",
+    "jkgcq",
+    "jkgfp",
+    "jkgfq",
+    "jkkph",
+    "jkkra",
+    "jkkro",
+    "jkkzb",
+    "jkkzm",
+    "jkkfm",
+    "jkqnh",
+    "jkqph",
+    "jkqab",
+    "jkqav",
+    "jkqbh",
+    "jkqfp",
+    "jkqhm",
+    "jkqja",
+    "jkqjk",
+    "jkwqb",
+    "jkwri",
+    "jkwzy",
+    "jkwzm",
+    "jkwfe",
+    "jkwfq",
+    "jkacq",
+    "jndvz",
+    "jnovk",
+    "jntvl",
+    "jntyv",
+    "jntev",
+    "jntfb",
+    "jntib",
+    "jntiv",
+    "jnvvb",
+    "jrdro",
+    "jreyb",
+    "jrpvz",
+    "ktfqv",
+    "ktkyv",
+    "ktkev",
+    "ktkfv",
+    "ktnyv",
+    "ktppb",
+    "ktpqv",
+    "ktvhj",
+    "ktwaq",
+    "kxcyq",
+    "kxcge",
+    "kxdno",
+    "kxdvq",
+    "kxdhd",
+    "kxdhq",
+    "kxeni",
+    "kxenv",
+    "kxebo",
+    "kxebv",
+    "kxeev",
+    "kxhro",
+    "kxktk",
+    "kxkgv",
+    "kxnnv",
+    "kxngx",
+    "kxnib",
+    "kxobk",
+    "kxoho",
+    "kxpvj",
+    "kxpfb",
+    "kxrbq",
+    "kxrge",
+    "kxtov",
+    "kxvro",
+    "kxvbu",
+    "kxwpb",
+    "kxxvi",
+    "kbcev",
+    "kberv",
+    "kbivq",
+    "kbnyv",
+    "kboyv",
+    "kbpro",
+    "kbptl",
+    "kbptv",
+    "kbqqb",
+    "kbttb",
+    "kbtgv",
+    "kbupl",
+    "kbvpe",
+    "kbvgl",
+    "kbvgv",
+    "kbxro",
+    "kbbmv",
+    "khftb",
+    "khftv",
+    "khizx",
+    "khkad",
+    "khnyv",
+    "khpbo",
+    "khphq",
+    "khrrv",
+    "khwoq",
+    "khwte",
+    "khyqv",
+    "khyri",
+    "khyav",
+    "khyev",
+    "khbrk",
+    "kkcsq",
+    "kkcvk",
+    "kkcxb",
+    "kkczx",
+    "kkcah",
+    "kkccb",
+    "kkcji",
+    "kkcjk",
+    "kkgna",
+    "kkgnj",
+    "kkgfp",
+    "kkkra",
+    "kkkro",
+    "kkksq",
+    "kkkyi",
+    "kkkah",
+    "kkkib",
+    "kkqvq",
+    "kkqyi",
+    "kkqab",
+    "kkqbi",
+    "kkqbm",
+    "kkqfp",
+    "kkqib",
+    "kkqjk",
+    "kkwva",
+    "kkwah",
+    "kkaro",
+    "kkayv",
+    "kneuv",
+    "knnyv",
+    "knozv",
+    "knocv",
+    "knppb",
+    "knuxv",
+    "knugv",
+    "knvpe",
+    "knxrq",
+    "kpcec",
+    "kpgyi",
+    "kpgyq",
+    "krkad",
+    "ltiro",
+    "ltiyb",
+    "lttyv",
+    "lttge",
+    "lturi",
+    "ltvrk",
+    "ltvro",
+    "luqav",
+    "lvnnq",
+    "lwkpq",
+    "lwksv",
+    "lxtvb",
+    "lztrq",
+    "lbiuq",
+    "lcgpq",
+    "ldkad",
+    "lecgb",
+    "ledbt",
+    "lefro",
+    "legpq",
+    "legtv",
+    "lehvk",
+    "lekqb",
+    "lekgb",
+    "leqcb",
+    "lewqb",
+    "lfcvi",
+    "lfdra",
+    "lfdro",
+    "lferb",
+    "lfrgv",
+    "lgcpq",
+    "lgfbt",
+    "lggzx",
+    "lggzv",
+    "lglbv",
+    "lgphf",
+    "lguhb",
+    "lgvro",
+    "lgvev",
+    "lgxbv",
+    "liqpe",
+    "liqkv",
+    "ljwni",
+    "ljwvm",
+    "lkcfb",
+    "lkgpq",
+    "lkqqb",
+    "lktbo",
+    "lkwcq",
+    "llunv",
+    "llvro",
+    "lmjro",
+    "lmjvz",
+    "lmjbp",
+    "lmwqb",
+    "locqb",
+    "logaq",
+    "logev",
+    "lokpq",
+    "loqxb",
+    "lqcpq",
+    "lqcyq",
+    "lqeri",
+    "lqgeq",
+    "lqkyb",
+    "lqkfq",
+    "lqrri",
+    "lqvbi",
+    "lqvex",
+    "lqwyq",
+    "lrkad",
+    "mtdyb",
+    "mterq",
+    "mtkaq",
+    "mtkev",
+    "mtkge",
+    "mtnfb",
+    "mtppv",
+    "mtpav",
+    "mttpb",
+    "mtvni",
+    "mtvgv",
+    "mtwyq",
+    "mtwax",
+    "mtxbo",
+    "mxcfq",
+    "mxeni",
+    "mxktk",
+    "mxnyx",
+    "mxnbk",
+    "mxozb",
+    "mxoho",
+    "mxppb",
+    "mxtni",
+    "mxtev",
+    "mxvni",
+    "mxvpe",
+    "mxvva",
+    "mxvhp",
+    "mxxro",
+    "mxyro",
+    "mbdro",
+    "mbdeb",
+    "mbehp",
+    "mbgya",
+    "mbgaa",
+    "mbgev",
+    "mbhge",
+    "mbhgv",
+    "mbiuq",
+    "mbnro",
+    "mbnrq",
+    "mbnyv",
+    "mbnzv",
+    "mbnge",
+    "mbpni",
+    "mbppe",
+    "mbpro",
+    "mbtfq",
+    "mbuuv",
+    "mbzro",
+    "mbbmv",
+    "mcqea",
+    "mecph",
+    "mecvi",
+    "mecvo",
+    "mecxb",
+    "mecxv",
+    "meczb",
+    "mecah",
+    "meceb",
+    "mecfe",
+    "mecfh",
+    "megph",
+    "megrq",
+    "megfe",
+    "mekph",
+    "mekro",
+    "mekad",
+    "mekaq",
+    "mekeq",
+    "meqnq",
+    "meqph",
+    "meqba",
+    "meqbo",
+    "meqex",
+    "meqfp",
+    "meqho",
+    "meqhq",
+    "meqjk",
+    "mewsc",
+    "mewva",
+    "mewxb",
+    "mewzb",
+    "mewad",
+    "mewah",
+    "mewfe",
+    "mewgb",
+    "mearo",
+    "mhczv",
+    "mheni",
+    "mhehp",
+    "mhitv",
+    "mhkfq",
+    "mhnvl",
+    "mhnyv",
+    "mhtnv",
+    "mhtpb",
+    "mhttb",
+    "mhttl",
+    "mhtgb",
+    "mhtge",
+    "mhtgv",
+    "mhthj",
+    "mhwaa",
+    "mharo",
+    "mkcvi",
+    "mkczb",
+    "mkcah",
+    "mkcha",
+    "mkgnh",
+    "mkgra",
+    "mkgro",
+    "mkgfe",
+    "mkkno",
+    "mkkra",
+    "mkkyi",
+    "mkkfh",
+    "mkkgw",
+    "mkqph",
+    "mkqaa",
+    "mkqaq",
+    "mkqfq",
+    "mkqge",
+    "mkqjk",
+    "mkqmb",
+    "mkwvq",
+    "mnftb",
+    "mnihb",
+    "mnnyv",
+    "mnptf",
+    "mnpxv",
+    "mnpav",
+    "mntbo",
+    "mntev",
+    "mnufv",
+    "mnbmv",
+    "nthsb",
+    "ntkyv",
+    "ntoro",
+    "ntozx",
+    "ntohq",
+    "ntufv",
+    "ntwqv",
+    "ntwtb",
+    "ntwaq",
+    "ntwmb",
+    "ntxri",
+    "ntyxv",
+    "ntaro",
+    "ntayv",
+    "ntbro",
+    "nxexl",
+    "nxgxv",
+    "nxgfb",
+    "nxpvb",
+    "nxpeb",
+    "naqfq",
+    "naqhi",
+    "nbcaq",
+    "nbfqv",
+    "nbrfv",
+    "nbtyv",
+    "nbtge",
+    "nbxrk",
+    "nbxro",
+    "necqb",
+    "necaa",
+    "neceb",
+    "necfp",
+    "necmb",
+    "negnj",
+    "negnk",
+    "nekqb",
+    "nekaq",
+    "neqnq",
+    "neqob",
+    "neqbj",
+    "neqev",
+    "neqfp",
+    "neqib",
+    "neace",
+    "ngcfe",
+    "ngqzb",
+    "nhfyv",
+    "nhkad",
+    "nhnrj",
+    "nhnyv",
+    "nhpna",
+    "nhpro",
+    "nhqqv",
+    "nhqrv",
+    "nhqsv",
+    "nhqfb",
+    "nhttb",
+    "nhwtb",
+    "nhwea",
+    "nkcpb",
+    "nkcqb",
+    "nkcsq",
+    "nkcvi",
+    "nkcvk",
+    "nkcaa",
+    "nkcaq",
+    "nkccb",
+    "nkcce",
+    "nkcfm",
+    "nkcfp",
+    "nkcgb",
+    "nkcib",
+    "nkciv",
+    "nkcmb",
+    "nkgnq",
+    "nkgra",
+    "nkgrk",
+    "nkgrq",
+    "nkkrc",
+    "nkkyi",
+    "nkkzb",
+    "nkkzv",
+    "nkkaa",
+    "nkkcb",
+    "nkqnk",
+    "nkqvk",
+    "nkqbj",
+    "nkqcb",
+    "nkqfp",
+    "nkqhm",
+    "nkqhq",
+    "nkqib",
+    "nkqji",
+    "nkqjk",
+    "nkwri",
+    "nkwsc",
+    "nkwaq",
+    "nncea",
+    "nncix",
+    "nngfp",
+    "nngfq",
+    "nnkqb",
+    "nnkya",
+    "nnkyb",
+    "nnkyq",
+    "nnkfb",
+    "nnnpe",
+    "nnnyv",
+    "nnool",
+    "nnozv",
+    "nnrcv",
+    "nnugl",
+    "nnugv",
+    "nrrfv",
+    "otdvq",
+    "otkev",
+    "otnib",
+    "otpqv",
+    "otrcv",
+    "ottqv",
+    "ottrj",
+    "ottcv",
+    "ottev",
+    "ottfe",
+    "otugb",
+    "otugv",
+    "otvpe",
+    "otvro",
+    "otwaq",
+    "otwgb",
+    "otxrk",
+    "otxbz",
+    "otbri",
+    "oxcqv",
+    "oxcea",
+    "oxceq",
+    "oxcge",
+    "oxcib",
+    "oxciv",
+    "oxftb",
+    "oxhgv",
+    "oxkfq",
+    "oxnvu",
+    "oxnyl",
+    "oxppb",
+    "oxtbk",
+    "obnyv",
+    "obptb",
+    "obrcl",
+    "obrcv",
+    "obvpe",
+    "ohcea",
+    "ohdov",
+    "ohkfq",
+    "ohnyv",
+    "ohoro",
+    "ohprv",
+    "ohpbo",
+    "ohtqb",
+    "ohtav",
+    "ohtfb",
+    "ohvri",
+    "ohvyv",
+    "ohwaa",
+    "ohwfb",
+    "ohxri",
+    "ohxro",
+    "ohyqv",
+    "ononk",
+    "onova",
+    "onobo",
+    "onoce",
+    "onohp",
+    "onppe",
+    "onpxv",
+    "ontev",
+    "onuxv",
+    "onufv",
+    "onvpe",
+    "orfel",
+    "orgax",
+    "orork",
+    "orrro",
+    "pvkyv",
+    "pvkad",
+    "pwgni",
+    "pwkbj",
+    "pwkbq",
+    "pwnro",
+    "pwayi",
+    "pznbl",
+    "peknz",
+    "pfctb",
+    "pfdhb",
+    "pfrri",
+    "pfryv",
+    "pgcab",
+    "pgdbu",
+    "pgeho",
+    "pgfru",
+    "pggcq",
+    "pggeq",
+    "pghro",
+    "pgibq",
+    "pgnnv",
+    "pgnrq",
+    "pgpro",
+    "pgrhq",
+    "pgvro",
+    "pgvel",
+    "phpvz",
+    "pkcgb",
+    "pkqav",
+    "plnrq",
+    "pluhb",
+    "pmeuv",
+    "poqev",
+    "qtwaq",
+    "qtbmv",
+    "qxnyv",
+    "qxtxv",
+    "qxvgv",
+    "qxyri",
+    "qbhsv",
+    "qhkaq",
+    "qhkfq",
+    "qhmro",
+    "qhnyv",
+    "qhwfq",
+    "qnftb",
+    "qnkpb",
+    "qnkpv",
+    "qnool",
+    "qnocv",
+    "qnpgx",
+    "qnpgl",
+    "qntbo",
+    "rtrcx",
+    "rttzx",
+    "rtanh",
+    "rxdny",
+    "racxf",
+    "rbpxv",
+    "rbqfh",
+    "rbvgv",
+    "rgcph",
+    "rgcib",
+    "rggna",
+    "rggra",
+    "rggri",
+    "rggyq",
+    "rgksb",
+    "rgqph",
+    "rgqyi",
+    "rgqjk",
+    "rhcyx",
+    "rkkyi",
+    "stdri",
+    "stdbo",
+    "stfrk",
+    "stfyb",
+    "stiro",
+    "stppb",
+    "stpxv",
+    "strri",
+    "strfb",
+    "stttb",
+    "sttix",
+    "stufl",
+    "stvpe",
+    "stvro",
+    "stvub",
+    "stvgb",
+    "stwte",
+    "staro",
+    "sxcpe",
+    "sxcsv",
+    "sxcxv",
+    "sxcaq",
+    "sxccq",
+    "sxcek",
+    "sxcfb",
+    "sxcfe",
+    "sxcfq",
+    "sxcib",
+    "sxftb",
+    "sxgpe",
+    "sxgev",
+    "sxhgv",
+    "sxini",
+    "sxitv",
+    "sxobk",
+    "sxoho",
+    "sxrro",
+    "sxxri",
+    "sxxro",
+    "sbdri",
+    "sbgtb",
+    "sbiuq",
+    "sbmrk",
+    "sbnnz",
+    "sbool",
+    "sbovq",
+    "sbprk",
+    "sbpro",
+    "sbptl",
+    "sbrva",
+    "sbvub",
+    "sbxro",
+    "sbxva",
+    "seczx",
+    "shczv",
+    "shcge",
+    "shdov",
+    "sheni",
+    "shehp",
+    "shftb",
+    "shhgv",
+    "shivz",
+    "shivk",
+    "shqcv",
+    "shqfb",
+    "shtev",
+    "shuro",
+    "shwfb",
+    "shwfv",
+    "shxro",
+    "shyro",
+    "shyyv",
+    "shani",
+    "sneva",
+    "snexv",
+    "snork",
+    "snocv",
+    "snpno",
+    "snppe",
+    "snptb",
+    "snrhp",
+    "sntpe",
+    "sntva",
+    "snugv",
+    "srkad",
+    "sroce",
+    "srppe",
+    "srtvz",
+    "ttent",
+    "tteul",
+    "tteel",
+    "ttfnj",
+    "ttfyv",
+    "tthvx",
+    "ttivz",
+    "ttizx",
+    "ttkmb",
+    "ttlbo",
+    "ttmro",
+    "ttool",
+    "ttozx",
+    "ttozv",
+    "ttptx",
+    "ttptb",
+    "ttptl",
+    "ttptv",
+    "ttpvx",
+    "ttpvz",
+    "ttpyv",
+    "ttpbo",
+    "ttryb",
+    "tttpe",
+    "tttev",
+    "tttfe",
+    "ttubk",
+    "ttufb",
+    "ttvpe",
+    "ttvrv",
+    "ttwib",
+    "ttzvj",
+    "ttaob",
+    "ttabo",
+    "txcyv",
+    "txcaq",
+    "txcgv",
+    "txepx",
+    "txfni",
+    "txfvx",
+    "txfvz",
+    "txnrb",
+    "txnbk",
+    "txtpv",
+    "txttb",
+    "txtvq",
+    "txtev",
+    "txvni",
+    "txvro",
+    "txvel",
+    "tbeel",
+    "tbftb",
+    "tbffq",
+    "tbiuq",
+    "tbnxv",
+    "tbovz",
+    "tbppb",
+    "tbpro",
+    "tbpvj",
+    "tbpbo",
+    "tbpgv",
+    "tbphp",
+    "tbtge",
+    "tburo",
+    "tbufv",
+    "theux",
+    "thfni",
+    "thfri",
+    "thfrj",
+    "thihi",
+    "thkfq",
+    "thnno",
+    "thnqv",
+    "thprv",
+    "thpge",
+    "thqqv",
+    "thqfb",
+    "thtni",
+    "thtbk",
+    "thtce",
+    "thufv",
+    "thvri",
+    "thvvc",
+    "thvbo",
+    "thvgl",
+    "thwyq",
+    "thwaa",
+    "thwaq",
+    "thwek",
+    "thwfb",
+    "thwge",
+    "thxro",
+    "thxvb",
+    "thyro",
+    "tnexv",
+    "tnehp",
+    "tnfqv",
+    "tnnpe",
+    "tnozv",
+    "tnppe",
+    "tntni",
+    "tntxv",
+    "tnuuv",
+    "tnuvz",
+    "tnuxv",
+    "tnugv",
+    "trtee",
+    "utfvo",
+    "utkib",
+    "utpav",
+    "utuni",
+    "utugv",
+    "utvni",
+    "utxni",
+    "utxri",
+    "uxgqv",
+    "uxkte",
+    "uxtqv",
+    "uxtib",
+    "uxxro",
+    "uxyro",
+    "uxyyv",
+    "ubero",
+    "ubeub",
+    "ubgpb",
+    "ubiuq",
+    "ubpwx",
+    "ubpav",
+    "ubpge",
+    "uhdyb",
+    "uhdyv",
+    "uhkfb",
+    "uhkfv",
+    "uhona",
+    "uhqfb",
+    "uhtge",
+    "uhurv",
+    "uhvpe",
+    "uhxri",
+    "unftb",
+    "untfb",
+    "unvgv",
+    "urnbk",
+    "uroce",
+    "vtmrk",
+    "vugfb",
+    "vveho",
+    "vvgnk",
+    "vvvni",
+    "vvvrq",
+    "vwfro",
+    "vwfyv",
+    "vyhni",
+    "vyhro",
+    "vyvrk",
+    "vefrk",
+    "vefro",
+    "vekib",
+    "vfdeb",
+    "vfgtx",
+    "vgkbk",
+    "vgurq",
+    "vigex",
+    "vikab",
+    "vikhj",
+    "vivvz",
+    "vkdvq",
+    "vkfro",
+    "vkink",
+    "vmjro",
+    "vmvro",
+    "vniuq",
+    "vnppb",
+    "vnvql",
+    "vnvro",
+    "vnvtl",
+    "vocev",
+    "vocgb",
+    "vogeq",
+    "vokab",
+    "voqva",
+    "vpkad",
+    "vppro",
+    "vqkqb",
+    "vsqab",
+    "wtfqv",
+    "wtink",
+    "wtkaq",
+    "wtnro",
+    "wtnfv",
+    "wtpri",
+    "wtpvz",
+    "wtpfv",
+    "wtrni",
+    "wtrro",
+    "wttro",
+    "wttxx",
+    "wttev",
+    "wttfb",
+    "wttgv",
+    "wtugx",
+    "wtugb",
+    "wtugv",
+    "wtvpe",
+    "wtvvl",
+    "wtvfv",
+    "wtvgv",
+    "wtwfb",
+    "wtarb",
+    "wtaro",
+    "wxcpb",
+    "wxcpe",
+    "wxcei",
+    "wxenk",
+    "wxfni",
+    "wxpni",
+    "wxppb",
+    "wxpab",
+    "wxpav",
+    "wxtpe",
+    "wxtvi",
+    "wxtxv",
+    "wxuxv",
+    "wxugl",
+    "wxvni",
+    "wxvgv",
+    "wacfb",
+    "waqab",
+    "waqav",
+    "waqgl",
+    "wbcal",
+    "wbexv",
+    "wbgpb",
+    "wbggv",
+    "wbitv",
+    "wbnbq",
+    "wbppe",
+    "wbprv",
+    "wbpxv",
+    "wbpgl",
+    "wbrro",
+    "wbshb",
+    "wbvpe",
+    "wbvuv",
+    "wbxbq",
+    "wbzri",
+    "wbzvb",
+    "wbbmx",
+    "wecpb",
+    "wecva",
+    "wecvk",
+    "wecvq",
+    "wecab",
+    "wecah",
+    "wecaq",
+    "wecgb",
+    "wecmx",
+    "wegna",
+    "wegnq",
+    "wekra",
+    "wekro",
+    "wewph",
+    "wewzy",
+    "wewzb",
+    "wewzm",
+    "wewah",
+    "wewfe",
+    "whgfv",
+    "whkaq",
+    "whkfb",
+    "whmro",
+    "whnno",
+    "whnxx",
+    "whnlm",
+    "whqpe",
+    "whrcv",
+    "whtpe",
+    "whuro",
+    "whuvq",
+    "whufb",
+    "whwpe",
+    "whwaa",
+    "whwgv",
+    "whyro",
+    "wkcah",
+    "wkcjk",
+    "wkgrk",
+    "wkgfp",
+    "wkkpb",
+    "wkkph",
+    "wkkqb",
+    "wkkra",
+    "wkkzb",
+    "wkkzl",
+    "wkkaq",
+    "wkkbo",
+    "wkkfj",
+    "wkkiv",
+    "wkkmb",
+    "wkqob",
+    "wkqab",
+    "wkqad",
+    "wkqbc",
+    "wkqfb",
+    "wkqha",
+    "wkqib",
+    "wkqji",
+    "wkqkv",
+    "wkwqb",
+    "wkwab",
+    "wlcyj",
+    "wndvz",
+    "wnftv",
+    "wnhsv",
+    "wnncv",
+    "wnnfb",
+    "wnppe",
+    "wnrni",
+    "wnrvi",
+    "wnrcv",
+    "wntrb",
+    "wntro",
+    "wnttb",
+    "wntfb",
+    "wnuuv",
+    "wnvgv",
+    "wrizv",
+    "xncph",
+    "xncvi",
+    "xncxb",
+    "xncyj",
+    "xnceh",
+    "xnceq",
+    "xncfe",
+    "xncff",
+    "xngrk",
+    "xngro",
+    "xngyi",
+    "xngev",
+    "xngfq",
+    "xnghb",
+    "xnkph",
+    "xnkrq",
+    "xnkyi",
+    "xnkyq",
+    "xnkeh",
+    "xnkgb",
+    "xnqgx",
+    "xnqgb",
+    "xnqge",
+    "ytdof",
+    "ytdva",
+    "ytero",
+    "ytfno",
+    "ytfvf",
+    "ytfvl",
+    "ytkav",
+    "ytkfb",
+    "ytlne",
+    "ytnyv",
+    "ytnce",
+    "ytork",
+    "ytppe",
+    "ytpqv",
+    "ytptb",
+    "ytrva",
+    "yttro",
+    "ytucv",
+    "ytvvl",
+    "ytvgv",
+    "ytxrk",
+    "ytabk",
+    "ytbbo",
+    "yxcpe",
+    "yxcpq",
+    "yxcqv",
+    "yxcyj",
+    "yxcej",
+    "yxdno",
+    "yxdri",
+    "yxdhp",
+    "yxdhq",
+    "yxenm",
+    "yxeho",
+    "yxehq",
+    "yxgqv",
+    "yxhro",
+    "yxhvq",
+    "yxini",
+    "yxjny",
+    "yxktk",
+    "yxnnu",
+    "yxnnv",
+    "yxnvz",
+    "yxovq",
+    "yxpni",
+    "yxprt",
+    "yxrnv",
+    "yxrri",
+    "yxryv",
+    "yxthk",
+    "yxurq",
+    "yxuvk",
+    "yxvpe",
+    "yxvel",
+    "yxvev",
+    "yxwfb",
+    "yxxri",
+    "yxxhb",
+    "yakal",
+    "yaazb",
+    "ybfro",
+    "ybftb",
+    "ybhyb",
+    "ybiuq",
+    "ybiva",
+    "ybibo",
+    "ybpfb",
+    "ybrrk",
+    "ybrro",
+    "yburk",
+    "yburo",
+    "ybuxv",
+    "ybxni",
+    "ybxro",
+    "ybxrq",
+    "yhcpe",
+    "yhcfq",
+    "yhdvk",
+    "yhdbq",
+    "yhexv",
+    "yhfrl",
+    "yhiro",
+    "yhihb",
+    "yhqzv",
+    "yhqfq",
+    "yhvbo",
+    "yhwtb",
+    "yhwte",
+    "yhwaa",
+    "yhwfb",
+    "yhwgb",
+    "yhxro",
+    "yhyqv",
+    "yhyro",
+    "yhani",
+    "ynfqv",
+    "ynfro",
+    "yniov",
+    "ynnro",
+    "ynoox",
+    "ynobo",
+    "ynrrb",
+    "yntni",
+    "ynugv",
+    "ztfyv",
+    "zthro",
+    "ztkaq",
+    "ztnna",
+    "ztnyv",
+    "ztnbk",
+    "ztnfx",
+    "ztngv",
+    "ztnib",
+    "ztnil",
+    "ztpqv",
+    "ztpro",
+    "ztrcv",
+    "ztufv",
+    "ztvvk",
+    "ztvlo",
+    "ztwpb",
+    "ztwpv",
+    "ztwax",
+    "ztwgb",
+    "ztxbo",
+    "ztxbv",
+    "ztxiv",
+    "zvcya",
+    "zvcyb",
+    "zvcym",
+    "zvcyv",
+    "zvczm",
+    "zvcaq",
+    "zvceb",
+    "zvcec",
+    "zvcev",
+    "zvgab",
+    "zvgaq",
+    "zvkbk",
+    "zvqsc",
+    "zvqya",
+    "zvqab",
+    "zvqbm",
+    "zvqcb",
+    "zvqeb",
+    "zvqek",
+    "zvqho",
+    "zvqhq",
+    "zvqji",
+    "zvtnj",
+    "zvtnm",
+    "zvtrb",
+    "zvtrt",
+    "zvthy",
+    "zvthj",
+    "zvwox",
+    "zxfnk",
+    "zxgqv",
+    "zxihb",
+    "zxkmb",
+    "zxork",
+    "zxpfb",
+    "zxrvx",
+    "zxtvc",
+    "zxthj",
+    "zxtib",
+    "zxvhm",
+    "zxxrk",
+    "zxxro",
+    "zxyro",
+    "zacph",
+    "zacqb",
+    "zacqv",
+    "zacsq",
+    "zacxb",
+    "zacxv",
+    "zacyb",
+    "zacyi",
+    "zacyq",
+    "zaczb",
+    "zacah",
+    "zaccb",
+    "zacea",
+    "zaceb",
+    "zaceh",
+    "zacem",
+    "zacib",
+    "zacji",
+    "zagno",
+    "zagrk",
+    "zagrm",
+    "zagro",
+    "zagrq",
+    "zagvh",
+    "zagyc",
+    "zagyi",
+    "zakra",
+    "zaksq",
+    "zakab",
+    "zakav",
+    "zakeb",
+    "zakeh",
+    "zakeq",
+    "zaqni",
+    "zaqph",
+    "zaqab",
+    "zaqbh",
+    "zaqbq",
+    "zaqeb",
+    "zaqek",
+    "zaqeq",
+    "zaqhq",
+    "zaqib",
+    "zaqjk",
+    "zaqjv",
+    "zatrt",
+    "zathy",
+    "zathd",
+    "zawph",
+    "zawaq",
+    "zawfe",
+    "zaayv",
+    "zbgtb",
+    "zbgib",
+    "zbiuq",
+    "zbizx",
+    "zbnxv",
+    "zbnyv",
+    "zbppb",
+    "zbprt",
+    "zbptb",
+    "zbtrk",
+    "zbufv",
+    "zbzge",
+    "zbzgv",
+    "zdcgb",
+    "zdkro",
+    "zdksc",
+    "zdkyi",
+    "zdkzm",
+    "zdkeq",
+    "zdwyh",
+    "zdwyi",
+    "zdwah",
+    "zecph",
+    "zecvk",
+    "zecad",
+    "zecaq",
+    "zecfe",
+    "zecgb",
+    "zecib",
+    "zegrh",
+    "zegrm",
+    "zegrq",
+    "zegcq",
+    "zekpb",
+    "zekph",
+    "zekqb",
+    "zekzb",
+    "zekzv",
+    "zekad",
+    "zekah",
+    "zeqbm",
+    "zeqcb",
+    "zeqfe",
+    "zeqge",
+    "zewzm",
+    "zewad",
+    "zewah",
+    "zewem",
+    "zewfe",
+    "zeayv",
+    "zfcph",
+    "zfcyi",
+    "zfceq",
+    "zfcfe",
+    "zfgno",
+    "zfgyi",
+    "zfgyq",
+    "zfkyb",
+    "zfkeh",
+    "zfkgb",
+    "zfkge",
+    "zfqph",
+    "zfqxb",
+    "zfqxv",
+    "zfqgb",
+    "zgcph",
+    "zgcvi",
+    "zgcxb",
+    "zgcxv",
+    "zgceb",
+    "zgcei",
+    "zggnh",
+    "zggro",
+    "zgkqb",
+    "zgksc",
+    "zgkcb",
+    "zgqbm",
+    "zgqeb",
+    "zgqeq",
+    "zgqhq",
+    "zgqjv",
+    "zgwph",
+    "zgwsc",
+    "zhccv",
+    "zhdro",
+    "zhitv",
+    "zhnno",
+    "zhnva",
+    "zhnib",
+    "zhpno",
+    "zhpvz",
+    "zhqge",
+    "zhqgv",
+    "zhtev",
+    "zhwaa",
+    "zhwge",
+    "zhyro",
+    "zicpb",
+    "zicqb",
+    "zicah",
+    "ziceb",
+    "ziceh",
+    "zicfj",
+    "zicjk",
+    "zignh",
+    "zigno",
+    "zigph",
+    "zigra",
+    "zigyi",
+    "zigyq",
+    "zigaa",
+    "zigaq",
+    "zigej",
+    "zikpb",
+    "zikpv",
+    "zikra",
+    "zikri",
+    "zikxb",
+    "zikxv",
+    "zikyi",
+    "zikyq",
+    "zikab",
+    "zikav",
+    "zikeb",
+    "zikgb",
+    "zinnq",
+    "zinvq",
+    "ziqvi",
+    "ziqxb",
+    "ziqbc",
+    "ziqbh",
+    "ziqbi",
+    "ziqbk",
+    "ziqeb",
+    "ziqeq",
+    "ziqhq",
+    "zitnv",
+    "zitrb",
+    "zitvd",
+    "ziwah",
+    "ziwek",
+    "ziweq",
+    "zjwna",
+    "zjwnq",
+    "zjwvy",
+    "zmcph",
+    "zmcsc",
+    "zmctb",
+    "zmcva",
+    "zmcvk",
+    "zmcvo",
+    "zmcxb",
+    "zmcyb",
+    "zmcyh",
+    "zmcyi",
+    "zmczm",
+    "zmcaa",
+    "zmcah",
+    "zmceb",
+    "zmceh",
+    "zmceq",
+    "zmcfe",
+    "zmcgb",
+    "zmcib",
+    "zmgna",
+    "zmgnh",
+    "zmgni",
+    "zmgnj",
+    "zmgra",
+    "zmgri",
+    "zmgrm",
+    "zmgro",
+    "zmgvk",
+    "zmgek",
+    "zmkph",
+    "zmksc",
+    "zmkyi",
+    "zmkyq",
+    "zmkad",
+    "zmkah",
+    "zmkaq",
+    "zmqph",
+    "zmqvz",
+    "zmqxb",
+    "zmqyb",
+    "zmqzm",
+    "zmqab",
+    "zmqav",
+    "zmqba",
+    "zmqbi",
+    "zmqbm",
+    "zmqeb",
+    "zmqeh",
+    "zmqej",
+    "zmqev",
+    "zmqhq",
+    "zmqib",
+    "zmtnm",
+    "zmtnt",
+    "zmtnv",
+    "zmtvm",
+    "zmthq",
+    "zmwph",
+    "zmwqv",
+    "zmwsc",
+    "zmwzm",
+    "zmwad",
+    "zmwah",
+    "zmwaq",
+    "zmayb",
+    "zncib",
+    "znino",
+    "znkad",
+    "znkgb",
+    "znnxv",
+    "znnyv",
+    "znonz",
+    "znpav",
+    "znrro",
+    "zntro",
+    "znttb",
+    "zntyv",
+    "znuuf",
+    "zpczf",
+    "zpczm",
+    "zpcej",
+    "zpcfe",
+    "zpcge",
+    "zpgno",
+    "zpgnq",
+    "zpgrm",
+    "zpgrq",
+    "zpgyi",
+    "zpgcq",
+    "zpksq",
+    "zpkyi",
+    "zpkab",
+    "zpkad",
+    "zpkei",
+    "zpkfe",
+    "zpqbk",
+    "zpqbm",
+    "zpqea",
+    "zpqeb",
+    "zpqek",
+    "zpwad",
+    "zrpba",
+    "zrthm",
+    "atdov",
+    "atdyb",
+    "atdbl",
+    "atevq",
+    "atexv",
+    "athsv",
+    "atkaq",
+    "atmrk",
+    "atmro",
+    "atnyv",
+    "atnbk",
+    "atoro",
+    "atptl",
+    "atptv",
+    "atrro",
+    "atrvo",
+    "attqv",
+    "attbq",
+    "atugb",
+    "atugv",
+    "atvgv",
+    "atwaq",
+    "atyav",
+    "axcpe",
+    "axcev",
+    "axcfb",
+    "axfqv",
+    "axgge",
+    "axocl",
+    "axprq",
+    "axpbo",
+    "axpfb",
+    "axpge",
+    "axrrb",
+    "axrva",
+    "axtex",
+    "axtfb",
+    "axugv",
+    "aacah",
+    "aagsq",
+    "aagvo",
+    "aagzb",
+    "aageb",
+    "aagfb",
+    "aaggx",
+    "aakph",
+    "aakrc",
+    "aakte",
+    "aakad",
+    "aakah",
+    "aakea",
+    "aaqad",
+    "aaqek",
+    "aaqfb",
+    "aatrb",
+    "aatrt",
+    "aatby",
+    "aatbt",
+    "aathj",
+    "aawzy",
+    "aawzm",
+    "aaazb",
+    "abcex",
+    "abdvx",
+    "abfni",
+    "abiro",
+    "abiuq",
+    "abnqb",
+    "aboro",
+    "abova",
+    "abrfv",
+    "abvnk",
+    "abvub",
+    "abvyb",
+    "ahcfq",
+    "ahfnv",
+    "ahfqv",
+    "ahmrk",
+    "ahpni",
+    "ahptx",
+    "ahpvz",
+    "ahqge",
+    "ahrnw",
+    "ahrvz",
+    "ahtpe",
+    "ahtfl",
+    "ahthp",
+    "ahvni",
+    "ahvrj",
+    "ahwpe",
+    "ahwte",
+    "ahyri",
+    "ahyro",
+    "ahzvz",
+    "ahzvk",
+    "akcpb",
+    "akcph",
+    "akcpq",
+    "akcqb",
+    "akcvi",
+    "akcvk",
+    "akcvq",
+    "akczm",
+    "akcfe",
+    "akcji",
+    "akgna",
+    "akgnq",
+    "akgaa",
+    "akkna",
+    "akkni",
+    "akkob",
+    "akkpb",
+    "akkph",
+    "akkra",
+    "akkcb",
+    "akkgb",
+    "akqyi",
+    "akqbm",
+    "akqcb",
+    "akqhq",
+    "akqib",
+    "akwpb",
+    "akwph",
+    "akwro",
+    "akwyv",
+    "akwzm",
+    "akwah",
+    "akwfp",
+    "akwfq",
+    "akwge",
+    "akafq",
+    "andni",
+    "andro",
+    "annvm",
+    "annyb",
+    "anobo",
+    "anpvz",
+    "antol",
+    "anvbo",
+    "apcad",
+    "apgnh",
+    "apgra",
+    "apgrq",
+    "apkpb",
+    "apkab",
+    "apkei",
+    "apkfq",
+    "apkkq",
+    "arkad",
+    "bwfro",
+    "beero",
+    "bevex",
+    "bfdex",
+    "bgeyb",
+    "bgehq",
+    "bgfro",
+    "bgfva",
+    "bgfhb",
+    "bghra",
+    "bghvq",
+    "bgksv",
+    "bgkbk",
+    "bgkgb",
+    "bgkgv",
+    "bgnvq",
+    "bgorq",
+    "bgurq",
+    "bgvvb",
+    "bgvvi",
+    "bgyra",
+    "bgbvm",
+    "birro",
+    "biurq",
+    "bkdnk",
+    "bkkab",
+    "blctb",
+    "bljro",
+    "blkad",
+    "blwni",
+    "blwem",
+    "bmkyb",
+    "bmvro",
+    "ctihb",
+    "ctnrq",
+    "ctnva",
+    "ctnbo",
+    "ctnhb",
+    "ctnib",
+    "ctrva",
+    "ctrbo",
+    "ctwyq",
+    "ctwaq",
+    "cxink",
+    "cxpbj",
+    "cxphb",
+    "cxttb",
+    "cxtfb",
+    "cxtfl",
+    "cxtib",
+    "cbeno",
+    "cbfrl",
+    "cbivi",
+    "cbibo",
+    "cbnyx",
+    "cbpli",
+    "cbqyx",
+    "cbrro",
+    "cbtni",
+    "cbthp",
+    "cbuvq",
+    "cbubo",
+    "cbugx",
+    "cbvni",
+    "cbxva",
+    "cbzrk",
+    "cheni",
+    "chfxx",
+    "chihb",
+    "chkpb",
+    "chkyx",
+    "chovq",
+    "chvro",
+    "chwpe",
+    "chyri",
+    "crkad",
+    "dtexv",
+    "dthro",
+    "dtiro",
+    "dtibk",
+    "dtkfq",
+    "dtkib",
+    "dtngw",
+    "dttgv",
+    "dtugb",
+    "dtvpe",
+    "dtvro",
+    "dtxro",
+    "dtzrk",
+    "dxcev",
+    "dxcib",
+    "dxftb",
+    "dxgqv",
+    "dxkte",
+    "dxkea",
+    "dxnpe",
+    "dxnfe",
+    "dxppe",
+    "dacph",
+    "dacyb",
+    "dacec",
+    "dagnq",
+    "dagri",
+    "dagym",
+    "dageb",
+    "dakpe",
+    "daksc",
+    "dakyb",
+    "dakab",
+    "dakav",
+    "dakei",
+    "dakfh",
+    "dakgb",
+    "daqyb",
+    "daqbm",
+    "daqfb",
+    "dbfrk",
+    "dbfro",
+    "dbfbt",
+    "dbfge",
+    "dbgya",
+    "dbiuq",
+    "dbnyv",
+    "dbocv",
+    "dbppb",
+    "dbppe",
+    "dbpqv",
+    "dburo",
+    "dbucv",
+    "dbvpe",
+    "dbvgv",
+    "dhmrk",
+    "dhonk",
+    "dhork",
+    "dhqqv",
+    "dhqro",
+    "dhqyv",
+    "dhqmv",
+    "dhtqv",
+    "dhtya",
+    "dhtev",
+    "dhtfb",
+    "dhtfq",
+    "dhtge",
+    "dhwya",
+    "dhwaa",
+    "dhxrk",
+    "dkcph",
+    "dkcge",
+    "dkgnh",
+    "dkgph",
+    "dkgfq",
+    "dkkad",
+    "dkkfq",
+    "dkkgb",
+    "dkqad",
+    "dkqgb",
+    "dkwad",
+    "dkayv",
+    "fteuq",
+    "fxcek",
+    "fxcfq",
+    "fbgya",
+    "fhwad",
+    "fhwge",
+    "gxdex",
+    "gxugv",
+    "ghpni",
 ];
 
 static ALLOWED_GUESSES: [&str; 10657] = [
@@ -13066,6 +13281,8 @@ static ALLOWED_GUESSES: [&str; 10657] = [
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+    use chrono::TimeZone;
     use super::*;
 
     #[test]
@@ -13077,15 +13294,101 @@ mod tests {
 
     #[test]
     fn test_not_allowed_guess() -> Result<(), ()> {
-        let cigar: Wordle = Wordle::new(['c', 'i', 'g', 'a', 'r']);
+        let mut cigar: Wordle = Wordle::new(['c', 'i', 'g', 'a', 'r']);
         let result = cigar.guess(['x'; 5]);
         result.map_or(Ok(()), |_| {Err(())})
     }
 
     #[test]
     fn test_wrong_guess() {
-        let cigar: Wordle = Wordle::new(['c', 'i', 'g', 'a', 'r']);
+        let mut cigar: Wordle = Wordle::new(['c', 'i', 'g', 'a', 'r']);
         let result = cigar.guess(['c', 'o', 'c', 'c', 'i']).unwrap();
-        assert_eq!(['c', 'o', 'c', 'c', 'i'].zip([Correct, Absent, Absent, Absent, Present]), result)
+        assert_eq!([('c', Correct), ('o', Absent), ('c', Absent), ('c', Absent), ('i', Present)], result)
+    }
+
+    #[test]
+    fn test_repeated_letters() {
+        assert_eq!([Absent, Present, Absent, Correct, Absent], score("cigar", ['a', 'r', 'r', 'a', 'y']));
+        assert_eq!([Absent, Absent, Present, Absent, Correct], score("crane", ['e', 'e', 'r', 'i', 'e']));
+        assert_eq!([Present, Absent, Absent, Absent, Absent], score("abbey", ['b', 'o', 'o', 'o', 'o']));
+    }
+
+    #[test]
+    fn test_adversarial_dodges_guesses() {
+        let mut wordle = Wordle::new_adversarial();
+        let hints = wordle.guess(['c', 'i', 'g', 'a', 'r']).unwrap();
+        assert!(hints.iter().any(|(_, h)| {*h != Correct}));
+        assert!(wordle.remaining() < answers().len());
+        assert!(wordle.remaining() > 1);
+        assert_eq!(Err(GuessError::NotInWordList), wordle.guess(['x'; 5]));
+    }
+
+    #[test]
+    fn test_adversarial_can_be_cornered() {
+        let mut wordle = Wordle::new_adversarial();
+        while wordle.remaining() > 1 {
+            let candidate = parse_word(&wordle.get_answer()).unwrap();
+            wordle.guess(candidate).unwrap();
+        }
+        let answer = parse_word(&wordle.get_answer()).unwrap();
+        assert_eq!(answer.map(|c| {(c, Correct)}), wordle.guess(answer).unwrap());
+    }
+
+    #[test]
+    fn test_puzzle_numbers() {
+        assert_eq!(0, puzzle_number(NaiveDate::from_ymd_opt(2022, 1, 1).unwrap()));
+        assert_eq!(31, puzzle_number(NaiveDate::from_ymd_opt(2022, 2, 1).unwrap()));
+        assert_eq!(word_for_puzzle(1), Wordle::new_for_date(NaiveDate::from_ymd_opt(2022, 1, 2).unwrap()).get_answer());
+        assert_eq!(word_for_puzzle(answers().len() as i64 - 1), word_for_puzzle(-1));
+    }
+
+    #[test]
+    fn test_answers_are_hidden() {
+        assert!(!HIDDEN_ANSWERS.contains(&"cigar"));
+        assert!(answers().contains(&"cigar"));
+        assert!(is_valid_guess("cigar"));
+        assert!(!is_valid_guess("CIGAR"));
+        assert_eq!("cigar", Secret::hide("cigar").reveal());
+        let mut wordle = Wordle::new(['c', 'i', 'g', 'a', 'r']);
+        assert_eq!(Ok([('c', Correct), ('i', Correct), ('g', Correct), ('a', Correct), ('r', Correct)]), wordle.guess(['c', 'i', 'g', 'a', 'r']));
+    }
+
+    #[test]
+    fn test_schedule_uses_every_answer_once_per_cycle() {
+        let days = (0..answers().len() as i64).map(word_for_puzzle).collect::<HashSet<_>>();
+        assert_eq!(answers().len(), days.len());
+        assert_ne!("cigar", word_for_puzzle(0));
+        assert_eq!(word_for_puzzle(7), word_for_puzzle(7 + answers().len() as i64));
+    }
+
+    #[test]
+    fn test_hint_symbols() {
+        [Correct, Present, Absent].iter().for_each(|hint| {
+            assert_eq!(Some(*hint), LetterHint::from_symbol(hint.symbol()));
+        });
+        assert_eq!(None, LetterHint::from_symbol('z'));
+    }
+
+    #[test]
+    fn test_key_states_cover_equation_symbols() {
+        let states = KeyStates::from_hints(&[('7', Correct), ('=', Present), ('/', Absent)]);
+        assert_eq!(Some(Correct), states.get('7'));
+        assert_eq!(Some(Present), states.get('='));
+        assert_eq!(Some(Absent), states.get('/'));
+        assert_eq!(None, states.get('+'));
+        assert_eq!(None, states.get('?'));
+    }
+
+    #[test]
+    fn test_key_states_keep_best_hint() {
+        let states = KeyStates::from_hints(&[
+            ('a', Correct), ('a', Present), ('a', Absent),
+            ('b', Present), ('b', Absent),
+            ('c', Absent), ('c', Present),
+        ]);
+        assert_eq!(Some(Correct), states.get('a'));
+        assert_eq!(Some(Present), states.get('b'));
+        assert_eq!(Some(Present), states.get('c'));
+        assert_eq!(None, states.get('d'));
     }
 }
\ No newline at end of file
@@ -0,0 +1,102 @@
+mod store;
+
+use std::env;
+use tiny_http::{Header, Method, Request, Response, Server};
+use ywd_core::{rank_scores, Score};
+use store::{FileStore, Store};
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:9002";
+const DEFAULT_STORE: &str = "scores.txt";
+
+/// The whole API: `POST /scores` with a score line, and `GET /scores/<puzzle>` for the ranked board.
+fn respond(store: &mut impl Store, method: &Method, url: &str, body: &str) -> (u16, String) {
+    let path = url.split('?').next().unwrap_or_default().trim_end_matches('/');
+    match (method, path.strip_prefix("/scores")) {
+        (Method::Options, _) => (204, String::new()),
+        (Method::Post, Some("")) => match body.trim().parse::<Score>() {
+            Ok(score) => match store.add(score) {
+                Ok(true) => (201, String::new()),
+                Ok(false) => (409, "already posted\n".to_string()),
+                Err(e) => (500, format!("{}\n", e)),
+            },
+            Err(_) => (400, "invalid score\n".to_string()),
+        },
+        (Method::Get, Some(puzzle)) => match puzzle.trim_start_matches('/').parse() {
+            Ok(puzzle) => {
+                let mut scores = store.scores(puzzle);
+                rank_scores(&mut scores);
+                (200, scores.iter().map(|score| {format!("{}\n", score)}).collect())
+            },
+            Err(_) => (400, "invalid puzzle\n".to_string()),
+        },
+        _ => (404, "not found\n".to_string()),
+    }
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).unwrap()
+}
+
+fn handle(store: &mut impl Store, mut request: Request) {
+    let mut body = String::new();
+    let (status, text) = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => respond(store, request.method(), request.url(), &body),
+        Err(_) => (400, "unreadable body\n".to_string()),
+    };
+    // Pages served from anywhere may post, since the game can be hosted separately.
+    let response = Response::from_string(text)
+        .with_status_code(status)
+        .with_header(header("Content-Type", "text/plain; charset=utf-8"))
+        .with_header(header("Access-Control-Allow-Origin", "*"))
+        .with_header(header("Access-Control-Allow-Methods", "GET, POST"))
+        .with_header(header("Access-Control-Allow-Headers", "Content-Type"));
+    if let Err(e) = request.respond(response) {
+        eprintln!("{}", e);
+    }
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let address = args.next().unwrap_or_else(|| {DEFAULT_ADDRESS.to_string()});
+    let path = args.next().unwrap_or_else(|| {DEFAULT_STORE.to_string()});
+    let mut store = match FileStore::open(&path) {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("cannot read {}: {}", path, e);
+            std::process::exit(1);
+        },
+    };
+    let server = match Server::http(&address) {
+        Ok(server) => server,
+        Err(e) => {
+            eprintln!("cannot listen on {}: {}", address, e);
+            std::process::exit(1);
+        },
+    };
+    println!("leaderboard listening on http://{}, keeping scores in {}", address, path);
+    for request in server.incoming_requests() {
+        handle(&mut store, request);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use store::MemoryStore;
+
+    #[test]
+    fn test_post_and_rank() {
+        let mut store = MemoryStore::default();
+        assert_eq!(201, respond(&mut store, &Method::Post, "/scores", "200 ann normal 50000 0 ....y-ggggg\n").0);
+        assert_eq!(201, respond(&mut store, &Method::Post, "/scores", "200 bob hard 90000 0 ggggg").0);
+        assert_eq!(201, respond(&mut store, &Method::Post, "/scores", "201 cat normal 10000 ggggg").0);
+        assert_eq!(409, respond(&mut store, &Method::Post, "/scores", "200 ann normal 1000 ggggg").0);
+        assert_eq!(400, respond(&mut store, &Method::Post, "/scores", "200 ann normal 1000 cigar").0);
+
+        let (status, body) = respond(&mut store, &Method::Get, "/scores/200", "");
+        assert_eq!(200, status);
+        assert_eq!("200 bob hard 90000 0 ggggg\n200 ann normal 50000 0 ....y-ggggg\n", body);
+        assert_eq!(400, respond(&mut store, &Method::Get, "/scores/today", "").0);
+        assert_eq!(404, respond(&mut store, &Method::Get, "/players", "").0);
+    }
+}
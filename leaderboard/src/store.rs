@@ -0,0 +1,84 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::PathBuf;
+use ywd_core::{is_new_score, Score};
+
+/// Where the server keeps scores. Returns `false` from `add` when the player already posted for that puzzle.
+pub trait Store {
+    fn add(&mut self, score: Score) -> io::Result<bool>;
+    fn scores(&self, puzzle: i64) -> Vec<Score>;
+}
+
+#[derive(Default)]
+pub struct MemoryStore(Vec<Score>);
+
+impl Store for MemoryStore {
+    fn add(&mut self, score: Score) -> io::Result<bool> {
+        let is_new = is_new_score(&self.0, &score);
+        if is_new {
+            self.0.push(score);
+        }
+        Ok(is_new)
+    }
+
+    fn scores(&self, puzzle: i64) -> Vec<Score> {
+        self.0.iter().filter(|score| {score.puzzle == puzzle}).cloned().collect()
+    }
+}
+
+/// Keeps every score in memory and appends each new one to a file, one per line.
+pub struct FileStore {
+    path: PathBuf,
+    scores: MemoryStore,
+}
+
+impl FileStore {
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            contents => contents?,
+        };
+        let scores = contents.lines()
+            .filter_map(|line| {line.parse().ok()})
+            .collect();
+        Ok(Self { path, scores: MemoryStore(scores) })
+    }
+}
+
+impl Store for FileStore {
+    fn add(&mut self, score: Score) -> io::Result<bool> {
+        if !is_new_score(&self.scores.0, &score) {
+            return Ok(false);
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        writeln!(file, "{}", score)?;
+        self.scores.add(score)
+    }
+
+    fn scores(&self, puzzle: i64) -> Vec<Score> {
+        self.scores.scores(puzzle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_file_store_survives_a_restart() {
+        let path = env::temp_dir().join(format!("ywd-scores-{}.txt", std::process::id()));
+        fs::remove_file(&path).ok();
+        let score = "200 ann normal 41200 gy..g-ggggg".parse::<Score>().unwrap();
+
+        let mut store = FileStore::open(&path).unwrap();
+        assert!(store.add(score.clone()).unwrap());
+        assert!(!store.add(score.clone()).unwrap());
+
+        let store = FileStore::open(&path).unwrap();
+        assert_eq!(vec![score], store.scores(200));
+        assert!(store.scores(201).is_empty());
+        fs::remove_file(&path).ok();
+    }
+}
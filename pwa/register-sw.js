@@ -0,0 +1,48 @@
+// Registers the service worker and bridges update events to the app:
+// `ywd-update-available` is dispatched on window when a new version is waiting,
+// and the app dispatches `ywd-apply-update` when the player chooses to reload.
+// `window.ywdUpdateWaiting` stays set too, for an app that starts listening late.
+(function () {
+  if (!('serviceWorker' in navigator)) {
+    return;
+  }
+  let waiting = null;
+
+  function announce(worker) {
+    waiting = worker;
+    window.ywdUpdateWaiting = true;
+    window.dispatchEvent(new Event('ywd-update-available'));
+  }
+
+  navigator.serviceWorker.register('sw.js').then((registration) => {
+    if (registration.waiting && navigator.serviceWorker.controller) {
+      announce(registration.waiting);
+    }
+    registration.addEventListener('updatefound', () => {
+      const installing = registration.installing;
+      installing.addEventListener('statechange', () => {
+        // Without a controller this is the first install, not an update.
+        if (installing.state === 'installed' && navigator.serviceWorker.controller) {
+          announce(installing);
+        }
+      });
+    });
+  });
+
+  // The first install claims the page too, which mustn't reload a game in progress.
+  let applying = false;
+  window.addEventListener('ywd-apply-update', () => {
+    if (waiting) {
+      applying = true;
+      waiting.postMessage('skip-waiting');
+    }
+  });
+
+  let reloading = false;
+  navigator.serviceWorker.addEventListener('controllerchange', () => {
+    if (applying && !reloading) {
+      reloading = true;
+      window.location.reload();
+    }
+  });
+})();
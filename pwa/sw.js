@@ -0,0 +1,52 @@
+// __BUILD__ is replaced with a timestamp on every `trunk build`, so each deploy gets a fresh cache
+// and browsers notice that the worker changed.
+const CACHE = 'ywd-__BUILD__';
+const INDEX = './';
+
+// Trunk fingerprints the js and wasm bundles, so their names are read out of the built index.html.
+async function precache() {
+  const cache = await caches.open(CACHE);
+  const response = await fetch(INDEX, { cache: 'reload' });
+  const html = await response.clone().text();
+  const assets = [...html.matchAll(/["']([^"']+\.(?:js|wasm))["']/g)].map((match) => match[1]);
+  await cache.put(INDEX, response);
+  await cache.addAll([...new Set(assets), 'manifest.webmanifest', 'icons/icon-192.png', 'icons/icon-512.png']);
+}
+
+self.addEventListener('install', (event) => {
+  event.waitUntil(precache());
+});
+
+self.addEventListener('activate', (event) => {
+  event.waitUntil(
+    caches.keys()
+      .then((keys) => Promise.all(keys.filter((key) => key !== CACHE).map((key) => caches.delete(key))))
+      .then(() => self.clients.claim()),
+  );
+});
+
+// The page asks for this once the player accepts the update prompt.
+self.addEventListener('message', (event) => {
+  if (event.data === 'skip-waiting') {
+    self.skipWaiting();
+  }
+});
+
+self.addEventListener('fetch', (event) => {
+  const request = event.request;
+  if (request.method !== 'GET' || new URL(request.url).origin !== self.location.origin) {
+    return;
+  }
+  if (request.mode === 'navigate') {
+    // Every route is the same app: try the network for a fresh page, fall back to the cached one.
+    event.respondWith(fetch(request).catch(() => caches.match(INDEX)));
+    return;
+  }
+  event.respondWith(
+    caches.match(request).then((cached) => cached || fetch(request).then((response) => {
+      const copy = response.clone();
+      caches.open(CACHE).then((cache) => cache.put(request, copy));
+      return response;
+    })),
+  );
+});
@@ -0,0 +1,66 @@
+use std::io::ErrorKind;
+use std::net::TcpStream;
+use std::sync::mpsc::channel;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tungstenite::{accept, Error, Message};
+use ywd_core::ClientMessage;
+use crate::room::Rooms;
+
+/// How long a read may block before queued broadcasts get written out.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+fn new_seed() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| {d.as_nanos() as u64})
+}
+
+/// Serves one player until they disconnect, reporting anything unexpected on stderr.
+pub fn handle(stream: TcpStream, rooms: Arc<Mutex<Rooms>>, started: Instant) {
+    let mut socket = match accept(stream) {
+        Ok(socket) => socket,
+        Err(e) => return eprintln!("handshake failed: {}", e),
+    };
+    if let Err(e) = socket.get_ref().set_read_timeout(Some(POLL_INTERVAL)) {
+        return eprintln!("{}", e);
+    }
+    let (outbox, inbox) = channel();
+    let mut joined: Option<(String, String)> = None;
+    let now = || {started.elapsed().as_millis() as u64};
+
+    let result = 'serve: loop {
+        match socket.read_message() {
+            Ok(Message::Text(text)) => {
+                let mut rooms = rooms.lock().unwrap();
+                match (text.parse::<ClientMessage>(), &joined) {
+                    (Ok(ClientMessage::Join { room, name }), None) => {
+                        if !rooms.join(&room, &name, now(), new_seed(), outbox.clone()) {
+                            break socket.close(None);
+                        }
+                        joined = Some((room, name));
+                    },
+                    (Ok(ClientMessage::Guess(hints)), Some((room, name))) => rooms.guess(room, name, hints),
+                    (Ok(ClientMessage::Finish(status)), Some((room, name))) => rooms.finish(room, name, status, now()),
+                    _ => (),
+                }
+            },
+            Ok(Message::Close(_)) => break Ok(()),
+            Ok(_) => (),
+            Err(Error::Io(e)) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => (),
+            Err(Error::ConnectionClosed | Error::AlreadyClosed) => break Ok(()),
+            Err(e) => break Err(e),
+        }
+        for message in inbox.try_iter() {
+            if let Err(e) = socket.write_message(Message::Text(message.to_string())) {
+                break 'serve Err(e);
+            }
+        }
+    };
+
+    if let Some((room, name)) = joined {
+        rooms.lock().unwrap().leave(&room, &name);
+    }
+    match result {
+        Ok(()) | Err(Error::ConnectionClosed | Error::AlreadyClosed) => (),
+        Err(e) => eprintln!("{}", e),
+    }
+}
@@ -0,0 +1,82 @@
+mod connection;
+mod room;
+
+use std::env;
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Instant;
+use room::Rooms;
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:9001";
+
+fn serve(listener: TcpListener) {
+    let rooms = Arc::new(Mutex::new(Rooms::default()));
+    let started = Instant::now();
+    for stream in listener.incoming().flatten() {
+        let rooms = rooms.clone();
+        thread::spawn(move || {connection::handle(stream, rooms, started)});
+    }
+}
+
+fn main() {
+    let address = env::args().nth(1).unwrap_or_else(|| {DEFAULT_ADDRESS.to_string()});
+    let listener = match TcpListener::bind(&address) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("cannot listen on {}: {}", address, e);
+            std::process::exit(1);
+        },
+    };
+    println!("relay listening on ws://{}", address);
+    serve(listener);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpStream;
+    use tungstenite::stream::MaybeTlsStream;
+    use tungstenite::{connect, Message, WebSocket};
+
+    fn client(address: &str) -> WebSocket<MaybeTlsStream<TcpStream>> {
+        connect(format!("ws://{}", address)).unwrap().0
+    }
+
+    fn send(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>, text: &str) {
+        socket.write_message(Message::Text(text.to_string())).unwrap();
+    }
+
+    fn receive(socket: &mut WebSocket<MaybeTlsStream<TcpStream>>) -> String {
+        socket.read_message().unwrap().into_text().unwrap()
+    }
+
+    #[test]
+    fn test_two_players_race_over_websockets() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        thread::spawn(move || {serve(listener)});
+
+        let mut ann = client(&address);
+        send(&mut ann, "join team ann");
+        let seed = receive(&mut ann);
+        assert!(seed.starts_with("seed "));
+
+        let mut bob = client(&address);
+        send(&mut bob, "join team bob");
+        assert_eq!(seed, receive(&mut bob));
+        assert_eq!("joined ann", receive(&mut bob));
+        assert_eq!("joined bob", receive(&mut ann));
+
+        send(&mut bob, "guess ggggg");
+        send(&mut bob, "finish won");
+        assert_eq!("progress bob ggggg", receive(&mut ann));
+        assert!(receive(&mut ann).starts_with("finished bob won 1 "));
+
+        drop(ann);
+        assert_eq!("progress bob ggggg", receive(&mut bob));
+        assert!(receive(&mut bob).starts_with("finished bob won 1 "));
+        assert_eq!("left ann", receive(&mut bob));
+        assert!(receive(&mut bob).starts_with("winner bob won 1 "));
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+use ywd_core::{race_winner, GameStatus, LetterHint, RaceResult, ServerMessage};
+
+struct Player {
+    name: String,
+    joined: u64,
+    rows: Vec<Vec<LetterHint>>,
+    result: Option<RaceResult>,
+    outbox: Sender<ServerMessage>,
+}
+
+struct Room {
+    seed: u64,
+    players: Vec<Player>,
+}
+
+impl Room {
+    fn broadcast(&self, message: &ServerMessage) {
+        self.players.iter().for_each(|player| {
+            player.outbox.send(message.clone()).ok();
+        });
+    }
+
+    fn player_mut(&mut self, name: &str) -> Option<&mut Player> {
+        self.players.iter_mut().find(|player| {player.name == name})
+    }
+
+    /// Announces the winner once nobody is still playing.
+    fn settle(&self) {
+        let results = self.players.iter()
+            .map(|player| {player.result.clone()})
+            .collect::<Option<Vec<_>>>();
+        if let Some(results) = results {
+            self.broadcast(&ServerMessage::Winner(race_winner(&results).cloned()));
+        }
+    }
+}
+
+/// Every open race, keyed by room name. Times are milliseconds on the server's clock.
+#[derive(Default)]
+pub struct Rooms(HashMap<String, Room>);
+
+impl Rooms {
+    /// Returns `false` if the name is already taken in that room.
+    pub fn join(&mut self, room: &str, name: &str, now: u64, seed: u64, outbox: Sender<ServerMessage>) -> bool {
+        let room = self.0.entry(room.to_string()).or_insert_with(|| {Room { seed, players: vec![] }});
+        if room.players.iter().any(|player| {player.name == name}) {
+            return false;
+        }
+        room.broadcast(&ServerMessage::Joined(name.to_string()));
+        outbox.send(ServerMessage::Seed(room.seed)).ok();
+        // Late joiners catch up on everything that happened so far.
+        for player in &room.players {
+            outbox.send(ServerMessage::Joined(player.name.clone())).ok();
+            for hints in &player.rows {
+                outbox.send(ServerMessage::Progress { name: player.name.clone(), hints: hints.clone() }).ok();
+            }
+            if let Some(result) = &player.result {
+                outbox.send(ServerMessage::Finished(result.clone())).ok();
+            }
+        }
+        room.players.push(Player { name: name.to_string(), joined: now, rows: vec![], result: None, outbox });
+        true
+    }
+
+    pub fn guess(&mut self, room: &str, name: &str, hints: Vec<LetterHint>) {
+        if let Some(room) = self.0.get_mut(room) {
+            match room.player_mut(name) {
+                Some(player) if player.result.is_none() => player.rows.push(hints.clone()),
+                _ => return,
+            }
+            room.broadcast(&ServerMessage::Progress { name: name.to_string(), hints });
+        }
+    }
+
+    pub fn finish(&mut self, room: &str, name: &str, status: GameStatus, now: u64) {
+        if let Some(room) = self.0.get_mut(room) {
+            let result = match room.player_mut(name) {
+                Some(player) if player.result.is_none() => {
+                    let result = RaceResult {
+                        name: name.to_string(),
+                        won: status == GameStatus::Won,
+                        guesses: player.rows.len(),
+                        millis: now.saturating_sub(player.joined),
+                    };
+                    player.result = Some(result.clone());
+                    result
+                },
+                _ => return,
+            };
+            room.broadcast(&ServerMessage::Finished(result));
+            room.settle();
+        }
+    }
+
+    pub fn leave(&mut self, room_name: &str, name: &str) {
+        if let Some(room) = self.0.get_mut(room_name) {
+            room.players.retain(|player| {player.name != name});
+            if room.players.is_empty() {
+                self.0.remove(room_name);
+            } else {
+                room.broadcast(&ServerMessage::Left(name.to_string()));
+                room.settle();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc::{channel, Receiver};
+    use ywd_core::LetterHint::{Absent, Correct};
+
+    fn received(inbox: &Receiver<ServerMessage>) -> Vec<String> {
+        inbox.try_iter().map(|message| {message.to_string()}).collect()
+    }
+
+    #[test]
+    fn test_race() {
+        let mut rooms = Rooms::default();
+        let (ann, ann_inbox) = channel();
+        let (bob, bob_inbox) = channel();
+        assert!(rooms.join("team", "ann", 0, 7, ann));
+        rooms.guess("team", "ann", vec![Absent; 5]);
+        assert!(rooms.join("team", "bob", 1000, 8, bob));
+        assert!(!rooms.join("team", "bob", 1000, 8, channel().0));
+        assert_eq!(vec!["seed 7", "joined ann", "progress ann ....."], received(&bob_inbox));
+
+        rooms.guess("team", "bob", vec![Correct; 5]);
+        rooms.finish("team", "bob", GameStatus::Won, 31_000);
+        rooms.guess("team", "ann", vec![Correct; 5]);
+        rooms.finish("team", "ann", GameStatus::Won, 20_000);
+        assert_eq!(vec![
+            "seed 7",
+            "progress ann .....",
+            "joined bob",
+            "progress bob ggggg",
+            "finished bob won 1 30000",
+            "progress ann ggggg",
+            "finished ann won 2 20000",
+            "winner bob won 1 30000",
+        ], received(&ann_inbox));
+    }
+
+    #[test]
+    fn test_leaving_settles_the_race() {
+        let mut rooms = Rooms::default();
+        let (ann, ann_inbox) = channel();
+        rooms.join("team", "ann", 0, 7, ann);
+        rooms.join("team", "bob", 0, 7, channel().0);
+        rooms.finish("team", "ann", GameStatus::Lost, 5000);
+        rooms.leave("team", "bob");
+        assert_eq!(Some("winner"), received(&ann_inbox).last().map(String::as_str));
+        rooms.leave("team", "ann");
+        assert!(rooms.0.is_empty());
+    }
+}
@@ -0,0 +1,49 @@
+use gloo::file::{Blob, ObjectUrl};
+use gloo::timers::callback::Timeout;
+use gloo::utils::document;
+use serde_json::{json, Value};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlAnchorElement;
+use crate::strings::strings;
+use ywd_core::{check_backup_version, Backup, BackupError, BACKUP_VERSION};
+
+const BACKUP_FILE: &str = "ywd-backup.json";
+
+/// Offers the player's data as a file to download. Stats and history keep the text formats they are
+/// stored in, wrapped with the version of the layout.
+pub fn export_backup(backup: &Backup) {
+    let json = json!({
+        "version": BACKUP_VERSION,
+        "stats": backup.stats,
+        "history": backup.history,
+    });
+    let blob = Blob::new_with_options(serde_json::to_string_pretty(&json).unwrap_or_default().as_str(), Some("application/json"));
+    let url = ObjectUrl::from(blob);
+    if let Ok(anchor) = document().create_element("a").map(|element| {element.unchecked_into::<HtmlAnchorElement>()}) {
+        anchor.set_href(&url);
+        anchor.set_download(BACKUP_FILE);
+        anchor.click();
+    }
+    // Revoking the address straight away can cancel the download in some browsers.
+    Timeout::new(1000, move || {drop(url)}).forget();
+}
+
+pub fn parse_backup(text: &str) -> Result<Backup, BackupError> {
+    let value = serde_json::from_str::<Value>(text).map_err(|_| BackupError::Malformed)?;
+    check_backup_version(value["version"].as_u64().ok_or(BackupError::Malformed)?)?;
+    let stats = value["stats"].as_str().ok_or(BackupError::Malformed)?;
+    let history = value["history"].as_array()
+        .and_then(|lines| {lines.iter().map(Value::as_str).collect::<Option<Vec<_>>>()})
+        .ok_or(BackupError::Malformed)?;
+    Backup::parse(stats, &history)
+}
+
+pub fn backup_error_message(error: BackupError) -> String {
+    let strings = strings();
+    match error {
+        BackupError::Malformed => strings.import_malformed.to_string(),
+        BackupError::UnsupportedVersion(_) => strings.import_version.to_string(),
+        BackupError::InvalidStats => strings.import_stats.to_string(),
+        BackupError::InvalidRecord(i) => format!("{} #{}", strings.import_record, i + 1),
+    }
+}
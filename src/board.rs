@@ -0,0 +1,76 @@
+use stylist::{css, StyleSource, YieldStyle};
+use yew::prelude::*;
+use yew::{Html, Properties};
+use crate::row::Row;
+use crate::tile::LetterState;
+
+pub struct Board;
+
+#[derive(Properties, PartialEq)]
+pub struct BoardProperties {
+    pub rows: Vec<Vec<(char, LetterState)>>,
+    #[prop_or_default]
+    pub shake_row: Option<usize>,
+    #[prop_or_default]
+    pub jump_row: Option<usize>,
+    /// Row and column of the tile being edited.
+    #[prop_or_default]
+    pub cursor: Option<(usize, usize)>,
+    #[prop_or_default]
+    pub on_tile_clicked: Option<Callback<usize>>,
+    /// When replaying a game, how many rows have been played back so far; later rows are drawn empty.
+    #[prop_or_default]
+    pub playback: Option<usize>,
+}
+
+impl Component for Board {
+    type Message = ();
+    type Properties = BoardProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        html! {
+            <div class={self.style()}
+                style={format!(
+                    "grid-template-rows: repeat({rows}, 1fr); width: min(350px, 100%, calc(var(--height) / {rows} * {columns}))",
+                    rows = props.rows.len(),
+                    columns = props.rows.first().map_or(5, |row| {row.len()}),
+                )}>{
+                props.rows.iter().enumerate().map(|(row_num, tiles)| {
+                    let tiles = match props.playback {
+                        Some(played) if row_num >= played => vec![(' ', LetterState::Initial); tiles.len()],
+                        _ => tiles.clone(),
+                    };
+                    let cursor = props.cursor.filter(|&(row, _)| {row == row_num}).map(|(_, column)| {column});
+                    let on_tile_clicked = cursor.and(props.on_tile_clicked.clone());
+                    html! {
+                        <Row
+                            tiles={tiles}
+                            shake={props.shake_row == Some(row_num)}
+                            jump={props.jump_row == Some(row_num)}
+                            cursor={cursor}
+                            on_tile_clicked={on_tile_clicked} />
+                    }
+                }).collect::<Html>()
+            }</div>
+        }
+    }
+}
+
+impl YieldStyle for Board {
+    fn style_from(&self) -> StyleSource<'static> {
+        css!(r#"
+            display: grid;
+            grid-gap: 5px;
+            padding: 10px;
+            box-sizing: border-box;
+            --height: var(--board-height, min(420px, calc(var(--vh, 100vh) - 310px)));
+            height: var(--height);
+            margin: 0px auto;
+        "#)
+    }
+}
@@ -1,164 +1,500 @@
+use std::iter;
+use js_sys::{Promise, Reflect};
+use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
-use std::fmt;
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::fmt::Formatter;
-use std::rc::Rc;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
 use gloo::events::EventListener;
-use gloo::timers::callback::Timeout;
-use gloo::utils::window;
+use gloo::storage::{LocalStorage, Storage};
+use gloo::timers::callback::{Interval, Timeout};
+use gloo::utils::{document, window};
 use stylist::{css, StyleSource, YieldStyle};
 use yew::{classes, Component, Context, Html};
 use yew::html::Scope;
 use yew::prelude::*;
-use web_sys::KeyboardEvent;
-use crate::game::GameState::InProgress;
-use crate::game::LetterState::Initial;
-use crate::Key;
-use crate::wordle::{LetterHint, Wordle};
+use yew_router::prelude::*;
+use web_sys::{ClipboardEvent, CompositionEvent, Event, HtmlInputElement, KeyboardEvent};
+use crate::backup::{backup_error_message, export_backup, parse_backup};
+use crate::board::Board;
+use crate::help::Help;
+use crate::history::{load_history, save_history, save_record, History as HistoryModal};
+use crate::leaderboard::submit_score;
+use crate::race::Race;
+use crate::result::GameResult;
+use crate::route::Route;
+use crate::statistics::Statistics;
+use crate::strings::strings;
+use crate::tile::{LetterState, FLIP_MS, REVEAL_STAGGER_MS};
+use crate::tile::LetterState::Initial;
+use crate::keyboard::Layout;
 use crate::Keyboard;
+use ywd_core::{
+    decode_challenge, decode_result, Backup, BackupError, encode_challenge, encode_result, format_duration, is_valid_name, result_status,
+    share_text, today_puzzle_number, versus_winner, Blitz, ClientMessage, Clock, GameRecord, GameSession, GameStatus, GuessError,
+    Hint, HintKind, Key, LetterHint, MultiSession, PasteError, Score, ServerMessage, Stats, Stopwatch, SystemClock, Versus,
+    VersusPhase, Wordle, BLITZ_MILLIS,
+};
 
-#[derive(Copy, Clone, PartialEq, Debug)]
-enum LetterState {
-    Initial,
-    Hint(LetterHint),
+const HELP_SEEN_KEY: &str = "ywd-help-seen";
+const STATS_KEY: &str = "ywd-stats";
+/// Today's daily game as far as it has been played, so a reload picks it up again.
+const DAILY_KEY: &str = "ywd-daily";
+/// The number of the last daily counted in the stats, history and leaderboard.
+const LAST_DAILY_KEY: &str = "ywd-last-daily";
+/// How long a finger has to rest on the board before it pastes.
+const LONG_PRESS_MS: u32 = 600;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_namespace = ["navigator", "clipboard"], js_name = readText)]
+    fn read_clipboard_text() -> Result<Promise, JsValue>;
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum GameMode {
+    Daily,
+    Practice,
+    Archive(i64),
+    Challenge([char; 5]),
+    Adversarial,
+    Dordle,
+    Quordle,
+    Octordle,
+    Nerdle,
+    Timed,
+    Blitz,
+    Race(String),
+    Versus,
+    /// Answering a versus link that carries the opponent's word.
+    VersusChallenge([char; 5]),
+    /// The opponent's word and how they did on ours, as colours only.
+    VersusReply([char; 5], Vec<Vec<LetterHint>>),
 }
 
-impl fmt::Display for LetterState {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", match self {
-            Self::Hint(hint) => format!("{:?}", hint),
-            s => format!("{:?}", s),
+impl GameMode {
+    const ALL: [GameMode; 10] = [
+        Self::Daily, Self::Practice, Self::Adversarial, Self::Dordle, Self::Quordle, Self::Octordle, Self::Nerdle,
+        Self::Timed, Self::Blitz, Self::Versus,
+    ];
+
+    /// `None` for a challenge or versus link that doesn't decode, an archive puzzle that isn't out yet, or a
+    /// race room with an unusable name.
+    fn from_route(route: &Route) -> Option<Self> {
+        Some(match route {
+            // Stats and history only get here when the page is opened on them; they show over the daily game.
+            Route::Home | Route::Daily | Route::Stats | Route::History | Route::NotFound => Self::Daily,
+            Route::Practice => Self::Practice,
+            // Only puzzles that have had their day, so an archive link can't give away an upcoming answer.
+            Route::Archive { n } if (0..=today_puzzle_number()).contains(n) => Self::Archive(*n),
+            Route::Archive { .. } => return None,
+            Route::Challenge { code } => Self::Challenge(decode_challenge(code)?),
+            Route::Adversarial => Self::Adversarial,
+            Route::Dordle => Self::Dordle,
+            Route::Quordle => Self::Quordle,
+            Route::Octordle => Self::Octordle,
+            Route::Nerdle => Self::Nerdle,
+            Route::Timed => Self::Timed,
+            Route::Blitz => Self::Blitz,
+            Route::Race { room } if is_valid_name(room) => Self::Race(room.clone()),
+            Route::Race { .. } => return None,
+            Route::Versus => Self::Versus,
+            Route::VersusChallenge { code } => Self::VersusChallenge(decode_challenge(code)?),
+            Route::VersusReply { code, result } => Self::VersusReply(decode_challenge(code)?, decode_result(result)?),
         })
     }
+
+    fn route(&self) -> Route {
+        match self {
+            Self::Daily => Route::Daily,
+            Self::Practice => Route::Practice,
+            Self::Archive(n) => Route::Archive { n: *n },
+            Self::Challenge(word) => Route::Challenge { code: encode_challenge(*word) },
+            Self::Adversarial => Route::Adversarial,
+            Self::Dordle => Route::Dordle,
+            Self::Quordle => Route::Quordle,
+            Self::Octordle => Route::Octordle,
+            Self::Nerdle => Route::Nerdle,
+            Self::Timed => Route::Timed,
+            Self::Blitz => Route::Blitz,
+            Self::Race(room) => Route::Race { room: room.clone() },
+            Self::Versus => Route::Versus,
+            Self::VersusChallenge(word) => Route::VersusChallenge { code: encode_challenge(*word) },
+            Self::VersusReply(word, rows) => Route::VersusReply { code: encode_challenge(*word), result: encode_result(rows) },
+        }
+    }
+
+    fn new_session(&self, blitz: &Blitz) -> MultiSession {
+        match self {
+            Self::Daily => MultiSession::single(load_daily()),
+            Self::Timed => MultiSession::single(GameSession::of_the_day()),
+            // Races and versus games swap in their own word once it is known.
+            Self::Practice | Self::Race(_) | Self::Versus | Self::VersusChallenge(_) => {
+                MultiSession::single(GameSession::new(Wordle::new_practice(SystemClock.now())))
+            },
+            Self::Archive(n) => MultiSession::single(GameSession::new(Wordle::new_for_puzzle(*n))),
+            Self::Challenge(word) => MultiSession::single(GameSession::new(Wordle::new(*word))),
+            Self::Adversarial => MultiSession::single(GameSession::adversarial()),
+            Self::Dordle => MultiSession::daily(2),
+            Self::Quordle => MultiSession::daily(4),
+            Self::Octordle => MultiSession::daily(8),
+            Self::Nerdle => MultiSession::single(GameSession::equation_of_the_day()),
+            Self::Blitz => MultiSession::single(blitz.session()),
+            Self::VersusReply(word, _) => MultiSession::single(GameSession::new(Wordle::new(*word))),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        let strings = strings();
+        match self {
+            Self::Daily => strings.mode_daily,
+            Self::Practice => strings.mode_practice,
+            Self::Archive(_) => strings.mode_archive,
+            Self::Challenge(_) => strings.mode_challenge,
+            Self::Adversarial => strings.mode_adversarial,
+            Self::Dordle => strings.mode_dordle,
+            Self::Quordle => strings.mode_quordle,
+            Self::Octordle => strings.mode_octordle,
+            Self::Nerdle => strings.mode_nerdle,
+            Self::Timed => strings.mode_timed,
+            Self::Blitz => strings.mode_blitz,
+            Self::Race(_) => strings.mode_race,
+            Self::Versus | Self::VersusChallenge(_) | Self::VersusReply(..) => strings.mode_versus,
+        }
+    }
+
+    /// How history records name the mode.
+    fn slug(&self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Practice => "practice",
+            Self::Archive(_) => "archive",
+            Self::Challenge(_) => "challenge",
+            Self::Adversarial => "absurd",
+            Self::Dordle => "dordle",
+            Self::Quordle => "quordle",
+            Self::Octordle => "octordle",
+            Self::Nerdle => "nerdle",
+            Self::Timed => "timed",
+            Self::Blitz => "blitz",
+            Self::Race(_) => "race",
+            Self::Versus | Self::VersusChallenge(_) | Self::VersusReply(..) => "versus",
+        }
+    }
+
+    /// Which puzzle a history record was played on, or `-` for random and player-picked words.
+    fn puzzle_id(&self, blitz: &Blitz) -> String {
+        match self {
+            Self::Daily | Self::Timed | Self::Dordle | Self::Quordle | Self::Octordle | Self::Nerdle => {
+                today_puzzle_number().to_string()
+            },
+            Self::Archive(n) => n.to_string(),
+            Self::Blitz => blitz.puzzle().to_string(),
+            Self::Challenge(word) | Self::VersusChallenge(word) | Self::VersusReply(word, _) => encode_challenge(*word),
+            Self::Race(room) => room.clone(),
+            Self::Practice | Self::Adversarial | Self::Versus => "-".to_string(),
+        }
+    }
+
+    fn is_timed(&self) -> bool {
+        matches!(self, Self::Timed | Self::Blitz)
+    }
+
+    fn is_versus(&self) -> bool {
+        matches!(self, Self::Versus | Self::VersusChallenge(_) | Self::VersusReply(..))
+    }
+
+    /// Hints would make the clock, the adversary or the opponents meaningless.
+    fn allows_hints(&self) -> bool {
+        !matches!(self, Self::Adversarial | Self::Timed | Self::Blitz | Self::Race(_)) && !self.is_versus()
+    }
+
+    fn layout(&self) -> Layout {
+        match self {
+            Self::Nerdle => Layout::Equations,
+            _ => Layout::Letters,
+        }
+    }
+}
+
+/// The name to show for a mode as history records write it.
+pub fn mode_name(slug: &str) -> &'static str {
+    let strings = strings();
+    match slug {
+        "practice" => strings.mode_practice,
+        "archive" => strings.mode_archive,
+        "challenge" => strings.mode_challenge,
+        "absurd" => strings.mode_adversarial,
+        "dordle" => strings.mode_dordle,
+        "quordle" => strings.mode_quordle,
+        "octordle" => strings.mode_octordle,
+        "nerdle" => strings.mode_nerdle,
+        "timed" => strings.mode_timed,
+        "blitz" => strings.mode_blitz,
+        "race" => strings.mode_race,
+        "versus" => strings.mode_versus,
+        _ => strings.mode_daily,
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct GameProperties {
+    pub route: Route,
 }
 
 pub enum Msg {
     Press(Key),
+    Reveal(usize),
+    RevealEnd,
+    Tick,
+    MoveCursor(usize),
+    RequestPaste,
+    /// A finger went down on the board (`true`), or lifted or moved away (`false`).
+    Hold(bool),
+    Paste(String),
+    Hint(HintKind),
     ShowMessage(&'static str, u32),
     ClearMessage,
     Shake,
     StopShaking,
-    Win,
-}
-
-enum GameResult {
-    Unknown,
-    Failed,
-    Wined,
-}
-
-enum GameState {
-    InProgress,
-    Over(GameResult),
+    ShowHelp,
+    CloseHelp,
+    CloseResult,
+    CloseStats,
+    CloseHistory,
+    Export,
+    Import(Option<String>),
+    PopState,
+    UpdateAvailable,
+    ApplyUpdate,
+    Race(ServerMessage),
+    RaceClosed,
 }
 
 pub struct Game {
-    state: GameState,
-    wordle: Wordle,
-    guesses: Vec<[(char, LetterState); 5]>,
-    current_guess: Vec<char>,
-    letter_states: Rc<RefCell<HashMap<char, Option<LetterHint>>>>,
+    mode: GameMode,
+    session: MultiSession,
+    revealed_tiles: usize,
+    on_key_pressed: Callback<Key>,
+    on_tile_clicked: Callback<usize>,
     message: String,
     shake: bool,
-    _keyboard_listener: Option<EventListener>,
+    revealing: bool,
+    show_help: bool,
+    show_result: bool,
+    show_stats: bool,
+    show_history: bool,
+    /// Whether an open modal pushed a history entry, so the back button can close it.
+    modal_in_history: bool,
+    /// Whether the stats or history page was opened from a game, so closing it can go back to that game.
+    overlay_from_game: bool,
+    update_available: bool,
+    stopwatch: Stopwatch,
+    /// When the first key of the current game was pressed, for its history record.
+    started: Option<u64>,
+    blitz: Blitz,
+    race: Option<Race>,
+    versus: Option<Versus>,
+    _ticker: Option<Interval>,
+    /// The steps of the reveal in progress. Dropping them cancels it, so a new game isn't revealed by the old one.
+    _reveal_timers: Vec<Timeout>,
+    /// Fires when a touch on the board has lasted long enough to count as a long press.
+    _hold_timer: Option<Timeout>,
+    _listeners: Vec<EventListener>,
 }
 
 impl Component for Game {
     type Message = Msg;
-    type Properties = ();
+    type Properties = GameProperties;
 
     fn create(ctx: &Context<Self>) -> Self {
-        let state_map = ('a' ..= 'z')
-            .fold(HashMap::new(), |mut m, c| {
-                m.insert(c, None);
-                m
-            });
+        let blitz = new_blitz();
+        let route = &ctx.props().route;
+        let mode = GameMode::from_route(route).unwrap_or(GameMode::Daily);
+        let message = match GameMode::from_route(route) {
+            None => strings().invalid_link,
+            Some(GameMode::Race(_)) => strings().race_waiting,
+            Some(_) => "",
+        };
         Self {
-            state: InProgress,
-            wordle: Wordle::new_of_the_day(),
-            guesses: vec![],
-            current_guess: vec![],
-            letter_states: Rc::new(RefCell::new(state_map)),
-            message: "".to_string(),
+            session: mode.new_session(&blitz),
+            race: join_race(ctx.link(), &mode),
+            versus: new_versus(&mode),
+            mode,
+            revealed_tiles: 0,
+            on_key_pressed: ctx.link().callback(|key| {Msg::Press(key)}),
+            on_tile_clicked: ctx.link().callback(|i| {Msg::MoveCursor(i)}),
+            message: message.to_string(),
             shake: false,
-            _keyboard_listener: None,
+            revealing: false,
+            show_help: LocalStorage::get::<bool>(HELP_SEEN_KEY).is_err(),
+            show_result: false,
+            show_stats: *route == Route::Stats,
+            show_history: *route == Route::History,
+            modal_in_history: false,
+            overlay_from_game: false,
+            update_available: false,
+            stopwatch: Stopwatch::default(),
+            started: None,
+            blitz,
+            _ticker: None,
+            _reveal_timers: vec![],
+            _hold_timer: None,
+            _listeners: vec![],
         }
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
         let link = ctx.link();
         match msg {
-            Self::Message::Press(_) if let GameState::Over(_) = self.state => false,
-            Self::Message::Press(key) => match key {
-                _ if self.guesses.len() == 6 => {
-                    false
-                },
-                Key::Backspace if self.current_guess.len() > 0 => {
-                    self.current_guess.pop();
-                    true
-                },
-                Key::Letter(c) if self.current_guess.len() < 5 => {
-                    self.current_guess.push(c);
+            Self::Message::Press(key) if self.is_choosing() && !self.is_modal_open() => self.choose(link, key),
+            Self::Message::Press(_) if self.is_input_blocked() => false,
+            Self::Message::Press(key) => match self.press(link, key) {
+                Ok(true) if key == Key::Enter => {
+                    if self.mode.is_timed() {
+                        self.stopwatch.split();
+                    }
+                    if self.mode == GameMode::Timed && self.session.status() != GameStatus::InProgress {
+                        self.stop_clock();
+                    }
+                    self.report_progress();
+                    self.reveal(link);
                     true
                 },
-                Key::Enter if self.current_guess.len() < 5 => {
+                Ok(changed) => changed,
+                Err(GuessError::GameOver) => false,
+                Err(GuessError::NotEnoughLetters) => {
                     self.shake(link);
-                    self.show_message(link, "Not enough letters", 1000);
+                    self.show_message(link, strings().not_enough_letters, 1000);
                     false
                 },
-                Key::Enter if self.current_guess.len() == 5 => {
-                    let guess: [char; 5] = self.current_guess.as_slice().try_into().unwrap();
-                    let result = self.wordle.guess(guess);
-                    match result {
-                        Ok(hints) => {
-                            self.current_guess.clear();
-
-                            hints.iter().for_each(|(c, s)| {
-                                self.letter_states.borrow_mut().entry(*c).and_modify(|state| {
-                                    match s {
-                                        LetterHint::Correct => {
-                                            state.replace(s.clone());
-                                        },
-                                        LetterHint::Present => match state {
-                                            Some(LetterHint::Correct) => (),
-                                            _ => {
-                                                state.replace(s.clone());
-                                            },
-                                        },
-                                        LetterHint::Absent => match state {
-                                            None => {
-                                                state.replace(s.clone());
-                                            },
-                                            _ => (),
-                                        },
-                                    };
-                                });
-                            });
-
-                            self.guesses.push(hints
-                                .map(|(c, h)| {(c, LetterState::Hint(h))}));
-
-                            if hints.iter().all(|(_, h)| {*h == LetterHint::Correct}) {
-                                self.state = GameState::Over(GameResult::Unknown);
-                                let link = link.clone();
-                                Timeout::new(2000, move || {link.send_message(Self::Message::Win)})
-                                    .forget();
-                            } else if self.guesses.len() == 6 {
-                                self.state = GameState::Over(GameResult::Failed);
-                                self.message = self.wordle.get_answer().to_string();
-                            }
-
-                            true
-                        },
-                        _ => {
-                            self.shake(link);
-                            self.show_message(link, "Not in word list", 1000);
-                            false
-                        },
-                    }
+                Err(GuessError::NotInWordList) => {
+                    self.shake(link);
+                    self.show_message(link, strings().not_in_word_list, 1000);
+                    false
                 },
-                _ => false,
+            },
+            Self::Message::Paste(_) if self.is_input_blocked() => false,
+            Self::Message::Paste(text) => {
+                self.start_clock(link);
+                match self.session.paste(&text) {
+                    Ok(changed) => changed,
+                    Err(PasteError::WrongLength) => {
+                        self.shake(link);
+                        self.show_message(link, strings().paste_wrong_length, 1000);
+                        false
+                    },
+                    Err(PasteError::InvalidCharacter(_)) => {
+                        self.shake(link);
+                        self.show_message(link, strings().paste_invalid, 1000);
+                        false
+                    },
+                }
+            },
+            Self::Message::RequestPaste => {
+                // Long-pressing the board on a phone has no text field to paste into, so read the clipboard directly.
+                if let Ok(promise) = read_clipboard_text() {
+                    let link = link.clone();
+                    spawn_local(async move {
+                        if let Some(text) = JsFuture::from(promise).await.ok().and_then(|text| {text.as_string()}) {
+                            link.send_message(Msg::Paste(text));
+                        }
+                    });
+                }
+                false
+            },
+            Self::Message::Hold(true) => {
+                let link = link.clone();
+                self._hold_timer = Some(Timeout::new(LONG_PRESS_MS, move || {link.send_message(Msg::RequestPaste)}));
+                false
+            },
+            Self::Message::Hold(false) => {
+                self._hold_timer = None;
+                false
+            },
+            Self::Message::MoveCursor(_) if self.revealing || self.stopwatch.is_stopped() => false,
+            Self::Message::MoveCursor(i) if self.is_choosing() => {
+                self.versus.as_mut().is_some_and(|versus| {versus.move_cursor(i)})
+            },
+            Self::Message::MoveCursor(i) => self.session.move_cursor(i),
+            Self::Message::Reveal(index) => {
+                self.revealed_tiles = index + 1;
+                true
+            },
+            Self::Message::RevealEnd => {
+                self.revealing = false;
+                self.save_daily();
+                let finished = self.session.status() != GameStatus::InProgress;
+                // However often a daily is played again, it only counts the first time.
+                let counts = finished && (self.mode != GameMode::Daily || count_daily(today_puzzle_number()));
+                if counts {
+                    self.record_game();
+                }
+                match self.session.status() {
+                    status if self.mode == GameMode::Blitz && status != GameStatus::InProgress => {
+                        let message = match status {
+                            GameStatus::Won => strings().win.to_string(),
+                            _ => self.session.answers().join(" "),
+                        };
+                        self.flash_message(link, message);
+                        self.blitz.record(status);
+                        self.session = MultiSession::single(self.blitz.session());
+                        self.revealed_tiles = 0;
+                    },
+                    GameStatus::Won => self.message = strings().win.to_string(),
+                    GameStatus::Lost => self.message = self.session.answers().join(" "),
+                    GameStatus::InProgress if self.mode == GameMode::Adversarial => {
+                        let remaining = self.session.boards()[0].remaining_answers();
+                        self.flash_message(link, format!("{} {}", remaining, strings().words_remaining));
+                    },
+                    GameStatus::InProgress => (),
+                }
+                if self.mode == GameMode::Daily && finished {
+                    self.stopwatch.stop();
+                }
+                if self.mode == GameMode::Daily && counts {
+                    record_stats(&self.session);
+                    submit_score(Score {
+                        // Filled in with the name the player picked.
+                        player: String::new(),
+                        puzzle: today_puzzle_number(),
+                        rows: hints_of(&self.session.boards()[0]),
+                        // The web game has no hard mode yet.
+                        hard_mode: false,
+                        millis: self.stopwatch.elapsed(),
+                        hints: self.session.hints_used(),
+                    });
+                }
+                if self.versus.as_mut().is_some_and(|versus| {versus.take_turn(&mut self.session)}) {
+                    self.flash_message(link, turn_message(self.versus_turn()));
+                }
+                let versus_over = self.mode.is_versus() && self.session.status() != GameStatus::InProgress;
+                if (self.mode == GameMode::Timed && self.stopwatch.is_stopped()) || versus_over {
+                    self.show_result = true;
+                    self.push_modal();
+                }
+                true
+            },
+            Self::Message::Tick if self.mode == GameMode::Blitz && self.blitz.is_over(self.stopwatch.elapsed()) => {
+                self.stop_clock();
+                self.message = strings().time_up.to_string();
+                self.show_result = true;
+                self.push_modal();
+                true
+            },
+            Self::Message::Tick => true,
+            Self::Message::Hint(_) if self.revealing || !self.mode.allows_hints() => false,
+            Self::Message::Hint(kind) => {
+                let strings = strings();
+                match self.session.hint(kind) {
+                    Some(Hint::Letter(i, c)) => self.flash_message(
+                        link, format!("{} {}: {}", strings.hint_position, i + 1, c.to_ascii_uppercase()),
+                    ),
+                    Some(Hint::Eliminate(chars)) => self.flash_message(
+                        link, format!("{}: {}", strings.hint_removed, chars.iter().collect::<String>().to_uppercase()),
+                    ),
+                    None => self.show_message(link, strings.no_hints, 1000),
+                }
+                self.save_daily();
+                true
             },
             Self::Message::ClearMessage => {
                 self.message = "".to_string();
@@ -182,63 +518,465 @@ impl Component for Game {
                     .forget();
                 true
             },
-            Self::Message::Win => {
-                self.state = GameState::Over(GameResult::Wined);
-                self.message = "win !!!".to_string();  // todo
+            Self::Message::ShowHelp => {
+                self.show_help = true;
+                self.push_modal();
+                true
+            },
+            Self::Message::CloseHelp | Self::Message::CloseResult => self.close_modal(),
+            Self::Message::CloseStats | Self::Message::CloseHistory => {
+                self.close_overlay(link);
+                false
+            },
+            Self::Message::Export => {
+                export_backup(&Backup { stats: load_stats(), history: load_history() });
+                false
+            },
+            Self::Message::Import(text) => {
+                match text.ok_or(BackupError::Malformed).and_then(|text| {parse_backup(&text)}) {
+                    Ok(imported) => {
+                        let merged = Backup { stats: load_stats(), history: load_history() }.merge(&imported);
+                        LocalStorage::set(STATS_KEY, merged.stats.to_string()).ok();
+                        save_history(&merged.history);
+                        self.flash_message(link, strings().import_done.to_string());
+                    },
+                    Err(error) => self.flash_message(link, backup_error_message(error)),
+                }
+                true
+            },
+            Self::Message::UpdateAvailable => {
+                self.update_available = true;
                 true
             },
+            Self::Message::ApplyUpdate => {
+                // register-sw.js activates the new worker, which reloads the page.
+                if let Ok(event) = Event::new("ywd-apply-update") {
+                    window().dispatch_event(&event).ok();
+                }
+                false
+            },
+            Self::Message::Race(message) => {
+                let race = match &mut self.race {
+                    Some(race) => race,
+                    None => return false,
+                };
+                race.receive(&message);
+                let own_name = race.name.clone();
+                let strings = strings();
+                match message {
+                    ServerMessage::Seed(seed) => {
+                        self.session = MultiSession::single(GameSession::new(Wordle::new_practice(seed)));
+                        self.started = None;
+                        self.revealing = false;
+                        self._reveal_timers.clear();
+                        self.revealed_tiles = 0;
+                        self.message = "".to_string();
+                    },
+                    ServerMessage::Joined(name) => self.flash_message(link, format!("{} {}", name, strings.race_joined)),
+                    ServerMessage::Left(name) => self.flash_message(link, format!("{} {}", name, strings.race_left)),
+                    ServerMessage::Finished(result) if result.name != own_name => self.flash_message(
+                        link, format!("{} {} {}", result.name, strings.race_finished, format_duration(result.millis)),
+                    ),
+                    ServerMessage::Finished(_) | ServerMessage::Progress { .. } => (),
+                    ServerMessage::Winner(Some(result)) => self.message = format!("{} {}", result.name, strings.race_winner),
+                    ServerMessage::Winner(None) => self.message = strings.race_no_winner.to_string(),
+                }
+                true
+            },
+            Self::Message::RaceClosed => {
+                self.race = None;
+                self.message = strings().race_disconnected.to_string();
+                true
+            },
+            Self::Message::PopState => {
+                self.modal_in_history = false;
+                self.dismiss_modals();
+                true
+            },
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>) -> bool {
+        let route = &ctx.props().route;
+        self.show_stats = *route == Route::Stats;
+        self.show_history = *route == Route::History;
+        // These pages open over the game being played rather than starting another one.
+        self.overlay_from_game = self.show_stats || self.show_history;
+        if self.overlay_from_game {
+            return true;
         }
+        match GameMode::from_route(route) {
+            Some(mode) => self.switch_mode(ctx.link(), mode),
+            None => {
+                self.switch_mode(ctx.link(), GameMode::Daily);
+                self.message = strings().invalid_link.to_string();
+            },
+        }
+        true
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let on_key_pressed = ctx.link().callback(|key| {Self::Message::Press(key)});
-        let letter_states = Rc::clone(&self.letter_states);
-        let rows = self.build_rows();
+        let on_key_pressed = self.on_key_pressed.clone();
+        let key_states = self.session.key_states_revealed(self.revealed_tiles);
+        let on_help = ctx.link().callback(|_| Self::Message::ShowHelp);
         html! {
             <div class={self.style()}>
                 {self.view_message()}
+                {self.view_update(ctx)}
+                {self.view_help(ctx)}
+                {self.view_result(ctx)}
+                {self.view_stats(ctx)}
+                {self.view_history(ctx)}
                 <header>
+                    <Link<Route> classes={classes!("stats-button")} to={Route::Stats}>{"📊"}</Link<Route>>
+                    <Link<Route> classes={classes!("history-button")} to={Route::History}>{"🕘"}</Link<Route>>
                     <h1>{"YDW"}</h1>
+                    <button class="help-button" onclick={on_help}>{"?"}</button>
                 </header>
-                {self.view_board(rows)}
-                <Keyboard on_key_pressed={on_key_pressed} letter_states={letter_states}/>
+                {self.view_modes()}
+                {self.view_clock()}
+                {self.view_turn()}
+                {self.view_hints(ctx)}
+                {self.view_boards(ctx)}
+                {self.view_opponents()}
+                <Keyboard on_key_pressed={on_key_pressed} key_states={key_states} layout={self.mode.layout()}/>
             </div>
         }
     }
 
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
         if first_render {
-            let onkeyup = ctx.link().batch_callback(|e: KeyboardEvent| {
-                match e.key().parse::<Key>() {
-                    Ok(key) => Some(Self::Message::Press(key)),
-                    _ => None
+            // Browser shortcuts keep working, and keys typed through an IME arrive with the composed text.
+            let onkeydown = ctx.link().batch_callback(|e: KeyboardEvent| {
+                if e.ctrl_key() || e.meta_key() || e.alt_key() || e.is_composing() || is_typing_in_field(&e) {
+                    return None;
+                }
+                let key = Key::from_key_event(&e.key(), &e.code())?;
+                if e.repeat() && key != Key::Backspace {
+                    return None;
                 }
+                e.prevent_default();
+                Some(Self::Message::Press(key))
             });
-            let event_listener = EventListener::new(&window(), "keyup", move |e| {
-                let e = e.clone();
-                onkeyup.emit(e.dyn_into::<KeyboardEvent>().unwrap())
+            let onpaste = ctx.link().batch_callback(|e: ClipboardEvent| {
+                if is_typing_in_field(&e) {
+                    return None;
+                }
+                let text = e.clipboard_data()?.get_data("text").ok()?;
+                e.prevent_default();
+                Some(Self::Message::Paste(text))
+            });
+            let oncompositionend = ctx.link().batch_callback(|e: CompositionEvent| {
+                e.data().unwrap_or_default()
+                    .chars()
+                    .filter_map(|c| {Key::from_key_event(&c.to_string(), "")})
+                    .map(Self::Message::Press)
+                    .collect::<Vec<_>>()
             });
-            self._keyboard_listener = Some(event_listener);
+            let link = ctx.link().clone();
+            let update_link = ctx.link().clone();
+            self._listeners = vec![
+                EventListener::new(&window(), "popstate", move |_| {link.send_message(Msg::PopState)}),
+                EventListener::new(&window(), "ywd-update-available", move |_| {
+                    update_link.send_message(Msg::UpdateAvailable)
+                }),
+                EventListener::new(&window(), "keydown", move |e| {
+                    onkeydown.emit(e.clone().dyn_into::<KeyboardEvent>().unwrap())
+                }),
+                EventListener::new(&window(), "paste", move |e| {
+                    onpaste.emit(e.clone().dyn_into::<ClipboardEvent>().unwrap())
+                }),
+                EventListener::new(&window(), "compositionend", move |e| {
+                    oncompositionend.emit(e.clone().dyn_into::<CompositionEvent>().unwrap())
+                }),
+            ];
+            // The worker may have been found waiting before the listener above existed.
+            let waiting = Reflect::get(&window(), &JsValue::from_str("ywdUpdateWaiting")).is_ok_and(|flag| {flag.is_truthy()});
+            if waiting {
+                ctx.link().send_message(Msg::UpdateAvailable);
+            }
         }
     }
 }
 
+/// Whether the event comes from a text field, e.g. the leaderboard name, which handles its own typing.
+fn is_typing_in_field(e: &Event) -> bool {
+    e.target().is_some_and(|target| {target.has_type::<HtmlInputElement>()})
+}
+
+fn load_stats() -> Stats {
+    LocalStorage::get::<String>(STATS_KEY).ok()
+        .and_then(|stats| {stats.parse::<Stats>().ok()})
+        .unwrap_or_default()
+}
+
+/// Today's daily, picked up where it was left if this browser has started it.
+fn load_daily() -> GameSession {
+    let today = GameSession::of_the_day();
+    LocalStorage::get::<GameSession>(DAILY_KEY).ok()
+        .filter(|saved| {saved.puzzle_state() == today.puzzle_state()})
+        .unwrap_or(today)
+}
+
+/// Marks a daily as counted, and tells whether it hadn't been already.
+fn count_daily(puzzle: i64) -> bool {
+    let counted = LocalStorage::get::<i64>(LAST_DAILY_KEY).ok() == Some(puzzle);
+    if !counted {
+        LocalStorage::set(LAST_DAILY_KEY, puzzle).ok();
+    }
+    !counted
+}
+
+fn record_stats(session: &MultiSession) {
+    let mut stats = load_stats();
+    stats.record(session.status(), session.turns(), session.hints_used());
+    LocalStorage::set(STATS_KEY, stats.to_string()).ok();
+}
+
+fn join_race(link: &Scope<Game>, mode: &GameMode) -> Option<Race> {
+    match mode {
+        GameMode::Race(room) => Race::join(room, link.callback(Msg::Race), link.callback(|_| Msg::RaceClosed)),
+        _ => None,
+    }
+}
+
+fn new_versus(mode: &GameMode) -> Option<Versus> {
+    match mode {
+        GameMode::Versus => Some(Versus::new()),
+        GameMode::VersusChallenge(word) => Some(Versus::answering(*word)),
+        _ => None,
+    }
+}
+
+/// A board's guesses as colours only.
+fn hints_of(board: &GameSession) -> Vec<Vec<LetterHint>> {
+    board.guesses().iter()
+        .map(|row| {row.iter().map(|&(_, hint)| {hint}).collect()})
+        .collect()
+}
+
+fn turn_message(player: usize) -> String {
+    let strings = strings();
+    format!("{} {}: {}", strings.versus_player, player + 1, strings.versus_turn)
+}
+
+/// An absolute link to `route`, for sending to someone else.
+fn link_to(route: &Route) -> String {
+    let base = document().base_uri().ok().flatten().unwrap_or_default();
+    format!("{}{}", base.trim_end_matches('/'), route.to_path())
+}
+
+fn new_blitz() -> Blitz {
+    // Any starting point will do, as long as consecutive runs don't replay the same words.
+    Blitz::new((SystemClock.now() / 1000) as i64, BLITZ_MILLIS)
+}
+
 impl Game {
-    fn build_rows(&self) -> [[(char, LetterState); 5]; 6] {
-        let mut rows = [[(' ', Initial); 5]; 6];
-        for (i, g) in self.guesses.iter().enumerate() {
-            rows[i] = *g;
-        }
-        if self.guesses.len() < 6 {
-            let mut current_guess = [(' ', Initial); 5];
-            for (i, c) in self.current_guess.iter().enumerate() {
-                current_guess[i].0 = *c;
+    fn switch_mode(&mut self, link: &Scope<Self>, mode: GameMode) {
+        if mode == self.mode {
+            return;
+        }
+        // Dropping the old race leaves its room.
+        self.race = join_race(link, &mode);
+        self.versus = new_versus(&mode);
+        self.message = match mode {
+            GameMode::Race(_) => strings().race_waiting.to_string(),
+            _ => "".to_string(),
+        };
+        self.mode = mode;
+        self.blitz = new_blitz();
+        self.session = self.mode.new_session(&self.blitz);
+        self.revealed_tiles = 0;
+        self.revealing = false;
+        self.show_result = false;
+        self.stopwatch = Stopwatch::default();
+        self.started = None;
+        self._ticker = None;
+        self._reveal_timers.clear();
+    }
+
+    fn is_modal_open(&self) -> bool {
+        self.show_help || self.show_result || self.show_stats || self.show_history
+    }
+
+    /// Whether typing is ignored right now, including while a race waits for its word.
+    fn is_input_blocked(&self) -> bool {
+        let waiting = matches!(self.mode, GameMode::Race(_)) && self.race.as_ref().map_or(true, |race| {race.seed.is_none()});
+        self.revealing || self.is_modal_open() || self.stopwatch.is_stopped() || waiting || self.is_choosing()
+    }
+
+    /// Whether a versus player is still picking a word, so keys go to that instead of the board.
+    fn is_choosing(&self) -> bool {
+        self.versus.as_ref().is_some_and(|versus| {matches!(versus.phase(), VersusPhase::Choosing(_))})
+    }
+
+    fn versus_turn(&self) -> usize {
+        match self.versus.as_ref().map(Versus::phase) {
+            Some(VersusPhase::Playing(turn)) => turn,
+            _ => 0,
+        }
+    }
+
+    fn choose(&mut self, link: &Scope<Self>, key: Key) -> bool {
+        let versus = match &mut self.versus {
+            Some(versus) => versus,
+            None => return false,
+        };
+        let hot_seat = versus.is_hot_seat();
+        match versus.choose(key) {
+            Ok(Some(session)) => {
+                self.session = session;
+                if hot_seat {
+                    self.flash_message(link, turn_message(0));
+                }
+                true
+            },
+            Ok(None) => true,
+            Err(GuessError::GameOver) => false,
+            Err(GuessError::NotEnoughLetters) => {
+                self.shake(link);
+                self.show_message(link, strings().not_enough_letters, 1000);
+                false
+            },
+            Err(GuessError::NotInWordList) => {
+                self.shake(link);
+                self.show_message(link, strings().not_in_word_list, 1000);
+                false
+            },
+        }
+    }
+
+    /// Tells the other racers how the last guess scored, letting them see its colours but not its letters.
+    fn report_progress(&self) {
+        if let Some(race) = &self.race {
+            let board = &self.session.boards()[0];
+            if let Some(guess) = board.guesses().last() {
+                race.send(&ClientMessage::Guess(guess.iter().map(|&(_, hint)| {hint}).collect()));
+            }
+            if board.status() != GameStatus::InProgress {
+                race.send(&ClientMessage::Finish(board.status()));
+            }
+        }
+    }
+
+    /// Gives the modal its own history entry, so going back closes it instead of leaving the page.
+    fn push_modal(&mut self) {
+        if !self.modal_in_history {
+            self.modal_in_history = window().history()
+                .and_then(|history| {history.push_state(&JsValue::NULL, "")})
+                .is_ok();
+        }
+    }
+
+    fn close_modal(&mut self) -> bool {
+        if self.modal_in_history {
+            // The popstate that follows dismisses the modal.
+            window().history().and_then(|history| {history.back()}).ok();
+            false
+        } else {
+            self.dismiss_modals();
+            true
+        }
+    }
+
+    /// Goes back to the game the stats or history page was opened from, or to the current mode's page when
+    /// that page was where the player came in.
+    fn close_overlay(&mut self, link: &Scope<Self>) {
+        if let Some(history) = link.history() {
+            if std::mem::take(&mut self.overlay_from_game) {
+                history.back();
+            } else {
+                history.push(self.mode.route());
+            }
+        }
+    }
+
+    fn dismiss_modals(&mut self) {
+        if self.show_help {
+            LocalStorage::set(HELP_SEEN_KEY, true).ok();
+        }
+        self.show_help = false;
+        self.show_result = false;
+    }
+
+    fn press(&mut self, link: &Scope<Self>, key: Key) -> Result<bool, GuessError> {
+        self.start_clock(link);
+        self.session.press(key)
+    }
+
+    fn start_clock(&mut self, link: &Scope<Self>) {
+        if self.session.status() != GameStatus::InProgress {
+            return;
+        }
+        self.started.get_or_insert_with(|| {SystemClock.now()});
+        if self.mode.is_timed() && self.stopwatch.start() {
+            let link = link.clone();
+            self._ticker = Some(Interval::new(100, move || {link.send_message(Msg::Tick)}));
+        } else if self.mode == GameMode::Daily {
+            // Daily games are timed quietly, for the leaderboard.
+            self.stopwatch.start();
+        }
+    }
+
+    /// Logs the game that just ended. A versus board is logged before the device changes hands, and a
+    /// blitz puzzle before the next one replaces it.
+    fn record_game(&mut self) {
+        let finished = SystemClock.now();
+        let started = self.started.take().unwrap_or(finished);
+        save_record(&GameRecord::new(self.mode.slug(), &self.mode.puzzle_id(&self.blitz), &self.session, started, finished));
+    }
+
+    fn save_daily(&self) {
+        if self.mode == GameMode::Daily {
+            LocalStorage::set(DAILY_KEY, &self.session.boards()[0]).ok();
+        }
+    }
+
+    fn stop_clock(&mut self) {
+        self.stopwatch.stop();
+        self._ticker = None;
+    }
+
+    fn build_rows(&self, board: &GameSession) -> Vec<Vec<(char, LetterState)>> {
+        let guesses = board.guesses();
+        let max_guesses = self.session.max_guesses();
+        let mut rows = vec![vec![(' ', Initial); self.session.width()]; max_guesses];
+        for (i, g) in guesses.iter().enumerate() {
+            rows[i] = g.iter().map(|&(c, h)| {(c, LetterState::Hint(h))}).collect();
+        }
+        if board.status() == GameStatus::InProgress && guesses.len() < max_guesses {
+            let current_guess = self.session.current_guess();
+            for (i, tile) in rows[guesses.len()].iter_mut().enumerate() {
+                tile.0 = current_guess.get(i).unwrap_or(' ');
             }
-            rows[self.guesses.len()] = current_guess;
         }
         rows
     }
 
+    /// Rows for a board known only by its colours.
+    fn build_hint_rows(&self, hints: &[Vec<LetterHint>]) -> Vec<Vec<(char, LetterState)>> {
+        let mut rows = vec![vec![(' ', Initial); self.session.width()]; self.session.max_guesses()];
+        for (row, hints) in rows.iter_mut().zip(hints) {
+            *row = hints.iter().map(|&hint| {(' ', LetterState::Hint(hint))}).collect();
+        }
+        rows
+    }
+
+    fn reveal(&mut self, link: &Scope<Self>) {
+        self.revealing = true;
+        self.revealed_tiles = 0;
+        let width = self.session.width();
+        self._reveal_timers = (0..width).map(|index| {
+            let link = link.clone();
+            let delay = index as u32 * REVEAL_STAGGER_MS + FLIP_MS / 2;
+            Timeout::new(delay, move || {link.send_message(Msg::Reveal(index))})
+        }).collect();
+        let link = link.clone();
+        self._reveal_timers.push(Timeout::new((width as u32 - 1) * REVEAL_STAGGER_MS + FLIP_MS, move || {
+            link.send_message(Msg::RevealEnd)
+        }));
+    }
+
     fn show_message(&self, link: &Scope<Self>, message: &'static str, millis: u32) {
         link.send_message(<Self as Component>::Message::ShowMessage(message, millis));
     }
@@ -247,6 +985,229 @@ impl Game {
         link.send_message(<Self as Component>::Message::Shake);
     }
 
+    fn flash_message(&mut self, link: &Scope<Self>, message: String) {
+        self.message = message;
+        let link = link.clone();
+        Timeout::new(1500, move || {link.send_message(Msg::ClearMessage)})
+            .forget();
+    }
+
+    fn view_help(&self, ctx: &Context<Self>) -> Html {
+        if self.show_help {
+            let on_close = ctx.link().callback(|_| Msg::CloseHelp);
+            html! {<Help on_close={on_close} />}
+        } else {
+            html! {}
+        }
+    }
+
+    fn view_result(&self, ctx: &Context<Self>) -> Html {
+        if !self.show_result {
+            return html! {};
+        }
+        if self.mode.is_versus() {
+            return self.view_versus_result(ctx);
+        }
+        let strings = strings();
+        let elapsed = self.stopwatch.elapsed();
+        let (lines, share) = match self.mode {
+            GameMode::Blitz => (
+                vec![format!("{}: {}/{}", strings.result_solved, self.blitz.solved(), self.blitz.played())],
+                self.blitz.share_text(),
+            ),
+            _ => (
+                iter::once(format!("{}: {}", strings.result_time, format_duration(elapsed)))
+                    .chain(self.stopwatch.splits().iter().enumerate().map(|(i, &split)| {
+                        format!("{} {}: {}", strings.result_guess, i + 1, format_duration(split))
+                    }))
+                    .collect(),
+                share_text(&format!("#{}", today_puzzle_number()), &self.session, Some(elapsed)),
+            ),
+        };
+        let on_close = ctx.link().callback(|_| Msg::CloseResult);
+        html! {<GameResult lines={lines} share={share} on_close={on_close} />}
+    }
+
+    fn view_hints(&self, ctx: &Context<Self>) -> Html {
+        if !self.mode.allows_hints() {
+            return html! {};
+        }
+        let strings = strings();
+        let disabled = self.revealing || !self.session.can_hint();
+        html! {
+            <nav class="hints">
+                <button disabled={disabled} onclick={ctx.link().callback(|_| Msg::Hint(HintKind::Letter))}>
+                    {strings.hint_letter}
+                </button>
+                <button disabled={disabled} onclick={ctx.link().callback(|_| Msg::Hint(HintKind::Eliminate))}>
+                    {strings.hint_eliminate}
+                </button>
+            </nav>
+        }
+    }
+
+    fn view_stats(&self, ctx: &Context<Self>) -> Html {
+        if !self.show_stats {
+            return html! {};
+        }
+        let on_close = ctx.link().callback(|_| Msg::CloseStats);
+        let on_export = ctx.link().callback(|_| Msg::Export);
+        let on_import = ctx.link().callback(Msg::Import);
+        html! {<Statistics stats={load_stats()} on_close={on_close} on_export={on_export} on_import={on_import} />}
+    }
+
+    fn view_history(&self, ctx: &Context<Self>) -> Html {
+        if !self.show_history {
+            return html! {};
+        }
+        let on_close = ctx.link().callback(|_| Msg::CloseHistory);
+        html! {<HistoryModal records={load_history()} on_close={on_close} />}
+    }
+
+    fn view_clock(&self) -> Html {
+        match self.mode {
+            GameMode::Timed => html! {
+                <div class="clock">{format_duration(self.stopwatch.elapsed())}</div>
+            },
+            GameMode::Blitz => html! {
+                <div class="clock">
+                    {format_duration(self.blitz.remaining(self.stopwatch.elapsed()))}
+                    {format!(" · {} {}", strings().result_solved, self.blitz.solved())}
+                </div>
+            },
+            _ => html! {},
+        }
+    }
+
+    fn view_turn(&self) -> Html {
+        match &self.versus {
+            Some(versus) if versus.is_hot_seat() && !self.is_choosing() => html! {
+                <div class="turn">{format!("{} {}", strings().versus_player, self.versus_turn() + 1)}</div>
+            },
+            _ => html! {},
+        }
+    }
+
+    fn view_choosing(&self, versus: &Versus) -> Html {
+        let strings = strings();
+        let entry = versus.entry();
+        let row = (0..entry.width()).map(|i| {(entry.get(i).unwrap_or(' '), Initial)}).collect::<Vec<_>>();
+        let player = versus.words().len();
+        // The first player's word can go out as a link instead of handing over the device.
+        let link = match versus.words() {
+            [word] if versus.is_hot_seat() => html! {
+                <p>
+                    {strings.versus_send_link}
+                    <input readonly=true value={link_to(&Route::VersusChallenge { code: encode_challenge(*word) })} />
+                </p>
+            },
+            _ => html! {},
+        };
+        html! {
+            <div class="choosing">
+                <p>{format!("{} {}: {}", strings.versus_player, player + 1, strings.versus_choose)}</p>
+                <Board
+                    rows={vec![row]}
+                    shake_row={if self.shake { Some(0) } else { None }}
+                    cursor={Some((0, entry.cursor()))}
+                    on_tile_clicked={self.on_tile_clicked.clone()} />
+                {link}
+            </div>
+        }
+    }
+
+    fn view_versus_result(&self, ctx: &Context<Self>) -> Html {
+        let strings = strings();
+        let on_close = ctx.link().callback(|_| Msg::CloseResult);
+        let board = &self.session.boards()[0];
+        // Each player: a label, their board, and its colours for judging and sharing.
+        let players = match (&self.mode, &self.versus) {
+            (GameMode::VersusChallenge(_), Some(versus)) => {
+                // Nothing to compare yet: the other player still has to find our word.
+                let route = Route::VersusReply { code: encode_challenge(versus.words()[1]), result: encode_result(&hints_of(board)) };
+                return html! {
+                    <GameResult lines={vec![strings.versus_send_reply.to_string()]} share={link_to(&route)} on_close={on_close} />
+                };
+            },
+            (GameMode::VersusReply(_, rows), _) => vec![
+                (strings.versus_you.to_string(), self.build_rows(board), hints_of(board)),
+                (strings.versus_opponent.to_string(), self.build_hint_rows(rows), rows.clone()),
+            ],
+            (_, Some(versus)) => {
+                let parked = match versus.parked() {
+                    Some(parked) => &parked.boards()[0],
+                    None => return html! {},
+                };
+                let player = |i: usize| {format!("{} {}", strings.versus_player, i + 1)};
+                let turn = self.versus_turn();
+                let mut players = vec![
+                    (player(turn), self.build_rows(board), hints_of(board)),
+                    (player(1 - turn), self.build_rows(parked), hints_of(parked)),
+                ];
+                if turn == 1 {
+                    players.reverse();
+                }
+                players
+            },
+            _ => return html! {},
+        };
+        let score = |hints: &[Vec<LetterHint>]| {(result_status(hints), hints.len())};
+        let line = match versus_winner([score(&players[0].2), score(&players[1].2)]) {
+            Some(winner) => format!("{} {}", players[winner].0, strings.race_winner),
+            None => strings.versus_draw.to_string(),
+        };
+        let share = players.iter()
+            .map(|(label, _, hints)| {
+                let grid = hints.iter()
+                    .map(|row| {row.iter().map(LetterHint::emoji).collect::<String>()})
+                    .collect::<Vec<_>>();
+                format!("{}\n{}", label, grid.join("\n"))
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        html! {
+            <GameResult lines={vec![line]} share={format!("YDW {}\n\n{}", strings.mode_versus, share)} on_close={on_close}>
+                <div class="boards">{
+                    players.into_iter().map(|(label, rows, _)| html! {
+                        <figure>
+                            <Board rows={rows} />
+                            <figcaption>{label}</figcaption>
+                        </figure>
+                    }).collect::<Html>()
+                }</div>
+            </GameResult>
+        }
+    }
+
+    fn view_modes(&self) -> Html {
+        html! {
+            <nav class="modes">{
+                GameMode::ALL
+                    .iter()
+                    .map(|mode| {
+                        let selected = if *mode == self.mode { Some("selected") } else { None };
+                        html! {
+                            <Link<Route> classes={classes!(selected)} to={mode.route()}>{mode.name()}</Link<Route>>
+                        }
+                    })
+                    .collect::<Html>()
+            }</nav>
+        }
+    }
+
+    fn view_update(&self, ctx: &Context<Self>) -> Html {
+        if !self.update_available {
+            return html! {};
+        }
+        let onclick = ctx.link().callback(|_| Msg::ApplyUpdate);
+        html! {
+            <div class="update">
+                {strings().update_available}
+                <button onclick={onclick}>{strings().update_reload}</button>
+            </div>
+        }
+    }
+
     fn view_message(&self) -> Html {
         match self.message.as_str() {
             "" => html! {},
@@ -256,36 +1217,76 @@ impl Game {
         }
     }
 
-    fn view_board(&self, rows: [[(char, LetterState); 5]; 6]) -> Html {
+    fn view_boards(&self, ctx: &Context<Self>) -> Html {
+        if let Some(versus) = self.versus.as_ref().filter(|_| {self.is_choosing()}) {
+            return self.view_choosing(versus);
+        }
+        let boards = self.session.boards();
+        let columns = match boards.len() {
+            1 => 1,
+            2 | 4 => 2,
+            _ => 4,
+        };
+        let board_rows = (boards.len() + columns - 1) / columns;
+        let multi_class = if boards.len() > 1 { Some("multi") } else { None };
+        // Only a long press by touch pastes; a right click keeps its menu and doesn't ask for the clipboard.
+        let ontouchstart = ctx.link().callback(|e: TouchEvent| {Msg::Hold(e.touches().length() == 1)});
+        let release = ctx.link().callback(|_: TouchEvent| {Msg::Hold(false)});
         html! {
-            <div id="board">{
-                rows.iter().enumerate().map(|(row_num, c2s)| {
-                    let shake_row_class = if self.shake && row_num == self.guesses.len() {
-                        Some("shake")
+            <div
+                class={classes!("boards", multi_class)}
+                ontouchstart={ontouchstart}
+                ontouchend={release.clone()}
+                ontouchcancel={release.clone()}
+                ontouchmove={release}
+                style={format!(
+                "grid-template-columns: repeat({}, 1fr); --board-height: calc(min(420px, calc(var(--vh, 100vh) - 310px)) / {})",
+                columns, board_rows,
+            )}>{
+                boards.iter().map(|board| {
+                    let guesses = board.guesses().len();
+                    let in_progress = board.status() == GameStatus::InProgress;
+                    let shake_row = if self.shake && in_progress { Some(guesses) } else { None };
+                    let jump_row = if board.status() == GameStatus::Won && !self.revealing {
+                        guesses.checked_sub(1)
                     } else { None };
-                    let jump_class = if let (GameState::Over(GameResult::Wined), true) = (&self.state, row_num + 1 == self.guesses.len()) {
-                        Some("jump")
+                    let cursor = if in_progress && !self.revealing {
+                        Some((guesses, self.session.current_guess().cursor()))
                     } else { None };
                     html! {
-                        <div class={classes!("row", shake_row_class, jump_class)}>{
-                            c2s.iter().enumerate().map(|(index, (c, s))| {
-                                let state_class = format!("{}", s);
-                                let filled_class = if *c == ' '{ None } else { Some("filled") };
-                                let revealed_class = if let LetterState::Initial = s {
-                                    None
-                                } else { Some("revealed") };
-                                html! {
-                                    <div class={classes!("tile", filled_class, revealed_class)}>
-                                        <div class="front" style={format!("transition-delay: {}ms", index * 300)}>{c}</div>
-                                        <div
-                                            class={classes!("back", state_class)}
-                                            style={format!("transition-delay: {}ms; animation-delay: {}ms", index * 300, index * 100)}>
-                                            {c}
-                                        </div>
-                                    </div>
-                                }
-                            }).collect::<Html>()
-                        }</div>
+                        <Board
+                            rows={self.build_rows(board)}
+                            shake_row={shake_row}
+                            jump_row={jump_row}
+                            cursor={cursor}
+                            on_tile_clicked={self.on_tile_clicked.clone()} />
+                    }
+                }).collect::<Html>()
+            }</div>
+        }
+    }
+
+    fn view_opponents(&self) -> Html {
+        let race = match &self.race {
+            Some(race) => race,
+            None => return html! {},
+        };
+        let max_guesses = self.session.max_guesses();
+        html! {
+            <div class="opponents">{
+                race.opponents.iter().map(|opponent| {
+                    let rows = self.build_hint_rows(&opponent.rows);
+                    let score = match &opponent.result {
+                        Some(result) if result.won => format!(" {}/{}", result.guesses, max_guesses),
+                        Some(_) => format!(" X/{}", max_guesses),
+                        None => "".to_string(),
+                    };
+                    let left_class = if opponent.left { Some("left") } else { None };
+                    html! {
+                        <figure class={classes!(left_class)}>
+                            <Board rows={rows} />
+                            <figcaption>{&opponent.name}{score}</figcaption>
+                        </figure>
                     }
                 }).collect::<Html>()
             }</div>
@@ -296,17 +1297,6 @@ impl Game {
 impl YieldStyle for Game {
     fn style_from(&self) -> StyleSource<'static> {
         css!(r#"
-            #board {
-                display: grid;
-                grid-template-rows: repeat(6, 1fr);
-                grid-gap: 5px;
-                padding: 10px;
-                box-sizing: border-box;
-                --height: min(420px, calc(var(--vh, 100vh) - 310px));
-                height: var(--height);
-                width: min(350px, calc(var(--height) / 6 * 5));
-                margin: 0px auto;
-            }
             .message {
                 position: absolute;
                 left: 50%;
@@ -314,163 +1304,140 @@ impl YieldStyle for Game {
                 color: #fff;
                 background-color: rgba(0, 0, 0, 0.85);
                 padding: 16px 20px;
-                z-index: 2;
+                z-index: 4;
                 border-radius: 4px;
                 transform: translateX(-50%);
                 transition: opacity 0.3s ease-out;
                 font-weight: 600;
             }
-            .row {
-                display: grid;
-                grid-template-columns: repeat(5, 1fr);
-                grid-gap: 5px;
+            h1 {
+                margin: 4px 0;
+                font-size: 36px;
             }
-            .tile {
-                width: 100%;
-                font-size: 2rem;
-                line-height: 2rem;
-                font-weight: bold;
-                vertical-align: middle;
-                text-transform: uppercase;
-                user-select: none;
+
+            header {
+                border-bottom: 1px solid #ccc;
+                margin-bottom: 30px;
                 position: relative;
             }
-            .tile.filled {
-                animation: zoom 0.2s;
+
+            .modes {
+                margin: -20px 0 10px;
             }
-            .tile .front,
-            .tile .back {
-                box-sizing: border-box;
-                display: inline-flex;
-                justify-content: center;
-                align-items: center;
-                position: absolute;
-                top: 0;
-                left: 0;
-                width: 100%;
-                height: 100%;
-                transition: transform 0.6s;
-                backface-visibility: hidden;
-                -webkit-backface-visibility: hidden;
+            .boards {
+                display: grid;
+                -webkit-touch-callout: none;
+                user-select: none;
             }
-            .tile .front {
-                border: 2px solid #d3d6da;
+            .clock {
+                font-weight: bold;
+                font-variant-numeric: tabular-nums;
             }
-            .tile.filled .front {
-                border-color: #999;
+            .turn {
+                font-weight: bold;
             }
-            .tile .back {
-                transform: rotateX(180deg);
+            .choosing {
+                --board-height: 80px;
             }
-            .tile.revealed .front {
-                transform: rotateX(180deg);
+            .choosing input {
+                display: block;
+                width: 100%;
+                margin-top: 4px;
+                box-sizing: border-box;
+                font-family: inherit;
             }
-            .tile.revealed .back {
-                transform: rotateX(0deg);
+            .opponents {
+                display: flex;
+                flex-wrap: wrap;
+                justify-content: center;
+                --board-height: 120px;
             }
-
-            @keyframes zoom {
-                0% {
-                    transform: scale(1.1);
-                }
-                100% {
-                    transform: scale(1);
-                }
+            .opponents figure {
+                margin: 0 4px;
+                font-size: 12px;
             }
-            .shake {
-                animation: shake 0.5s;
+            .opponents figure.left {
+                opacity: 0.4;
             }
-            @keyframes shake {
-                0% {
-                    transform: translate(1px);
-                }
-                10% {
-                    transform: translate(-2px);
-                }
-                20% {
-                    transform: translate(2px);
-                }
-                30% {
-                    transform: translate(-2px);
-                }
-                40% {
-                    transform: translate(2px);
-                }
-                50% {
-                    transform: translate(-2px);
-                }
-                60% {
-                    transform: translate(2px);
-                }
-                70% {
-                    transform: translate(-2px);
-                }
-                80% {
-                    transform: translate(2px);
-                }
-                90% {
-                    transform: translate(-2px);
-                }
-                100% {
-                    transform: translate(1px);
-                }
+            .boards.multi .tile {
+                font-size: 1rem;
+                line-height: 1rem;
             }
-
-            .jump .tile .back {
-                    animation: jump 0.5s;
+            .hints button {
+                margin: 4px;
+                padding: 2px 10px;
+                border: 1px dashed #c9b458;
+                border-radius: 4px;
+                background: none;
+                cursor: pointer;
+                font-family: inherit;
             }
-
-            @keyframes jump {
-                0% {
-                    transform: translateY(0px);
-                }
-                20% {
-                    transform: translateY(5px);
-                }
-                60% {
-                    transform: translateY(-25px);
-                }
-                90% {
-                    transform: translateY(3px);
-                }
-                100% {
-                    transform: translateY(0px);
-                }
+            .hints button:disabled {
+                opacity: 0.4;
+                cursor: default;
             }
-
-            @media (max-height: 680px) {
-                .tile {
-                    font-size: 3vh;
-                }
+            .modes a {
+                display: inline-block;
+                margin: 2px 4px;
+                padding: 4px 12px;
+                border: 1px solid #d3d6da;
+                border-radius: 4px;
+                color: inherit;
+                text-decoration: none;
             }
-
-            h1 {
-                margin: 4px 0;
-                font-size: 36px;
+            .modes a.selected {
+                color: #fff;
+                border-color: #6aaa64;
+                background-color: #6aaa64;
             }
 
-            header {
-                border-bottom: 1px solid #ccc;
-                margin-bottom: 30px;
-                position: relative;
+            .update {
+                padding: 8px;
+                background-color: #f4f4f5;
+                font-size: 14px;
             }
-
-            .Correct,
-            .Present,
-            .Absent {
-                color: #fff !important;
+            .update button {
+                margin-left: 8px;
+                border: 0;
+                border-radius: 4px;
+                padding: 4px 12px;
+                cursor: pointer;
+                font-family: inherit;
+                font-weight: bold;
+                color: #fff;
+                background-color: #6aaa64;
             }
-
-            .Correct {
-                background-color: #6aaa64 !important;
+            .stats-button {
+                position: absolute;
+                left: 8px;
+                top: 50%;
+                transform: translateY(-50%);
+                font-size: 24px;
+                text-decoration: none;
             }
 
-            .Present {
-                background-color: #c9b458 !important;
+            .history-button {
+                position: absolute;
+                left: 44px;
+                top: 50%;
+                transform: translateY(-50%);
+                font-size: 24px;
+                text-decoration: none;
             }
 
-            .Absent {
-                background-color: #787c7e !important;
+            .help-button {
+                position: absolute;
+                right: 8px;
+                top: 50%;
+                transform: translateY(-50%);
+                width: 28px;
+                height: 28px;
+                border: 2px solid #1a1a1b;
+                border-radius: 50%;
+                background: none;
+                cursor: pointer;
+                font-family: inherit;
+                font-weight: bold;
             }
         "#)
     }
@@ -0,0 +1,110 @@
+use stylist::{css, StyleSource, YieldStyle};
+use yew::prelude::*;
+use yew::{Html, Properties};
+use crate::row::Row;
+use crate::tile::LetterState;
+use crate::strings::strings;
+use ywd_core::LetterHint;
+
+pub struct Help;
+
+#[derive(Properties, PartialEq)]
+pub struct HelpProperties {
+    pub on_close: Callback<()>,
+}
+
+impl Component for Help {
+    type Message = ();
+    type Properties = HelpProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let strings = strings();
+        let on_close = ctx.props().on_close.reform(|_| ());
+        html! {
+            <div class={self.style()}>
+                <div class="modal">
+                    <h2>{strings.help_title}</h2>
+                    {strings.help_rules.iter().map(|rule| html! {<p>{rule}</p>}).collect::<Html>()}
+                    <hr />
+                    <h3>{strings.help_examples}</h3>
+                    {self.view_example("weary", 0, LetterHint::Correct, strings.help_correct)}
+                    {self.view_example("pills", 1, LetterHint::Present, strings.help_present)}
+                    {self.view_example("vague", 3, LetterHint::Absent, strings.help_absent)}
+                    <button onclick={on_close}>{strings.help_close}</button>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Help {
+    fn view_example(&self, word: &str, hinted: usize, hint: LetterHint, explanation: &str) -> Html {
+        let letter = word.chars().nth(hinted).unwrap();
+        let tiles = word.chars().enumerate().map(|(index, c)| {
+            if index == hinted {
+                (c, LetterState::Hint(hint))
+            } else {
+                (c, LetterState::Initial)
+            }
+        }).collect::<Vec<_>>();
+        html! {
+            <div class="example">
+                <Row tiles={tiles} />
+                <p><b>{letter.to_ascii_uppercase()}</b>{" "}{explanation}</p>
+            </div>
+        }
+    }
+}
+
+impl YieldStyle for Help {
+    fn style_from(&self) -> StyleSource<'static> {
+        css!(r#"
+            position: fixed;
+            top: 0;
+            left: 0;
+            width: 100%;
+            height: 100%;
+            z-index: 3;
+            background-color: rgba(255, 255, 255, 0.5);
+            display: flex;
+            justify-content: center;
+            align-items: center;
+            .modal {
+                max-width: 460px;
+                max-height: 90%;
+                overflow-y: auto;
+                padding: 16px 24px;
+                text-align: left;
+                background-color: #fff;
+                border-radius: 8px;
+                box-shadow: 0 4px 23px 0 rgba(0, 0, 0, 0.2);
+            }
+            h2 {
+                text-align: center;
+            }
+            .example .row {
+                width: 220px;
+            }
+            .example .tile {
+                height: 40px;
+                line-height: 40px;
+            }
+            button {
+                display: block;
+                margin: 16px auto 0;
+                padding: 8px 24px;
+                border: 0;
+                border-radius: 4px;
+                cursor: pointer;
+                font-family: inherit;
+                font-weight: bold;
+                color: #fff;
+                background-color: #6aaa64;
+            }
+        "#)
+    }
+}
@@ -0,0 +1,219 @@
+use gloo::storage::{LocalStorage, Storage};
+use gloo::timers::callback::Interval;
+use js_sys::Date;
+use stylist::{css, StyleSource, YieldStyle};
+use wasm_bindgen::JsValue;
+use yew::prelude::*;
+use yew::{Html, Properties};
+use crate::board::Board;
+use crate::game::mode_name;
+use crate::strings::strings;
+use crate::tile::{LetterState, FLIP_MS, REVEAL_STAGGER_MS};
+use ywd_core::{parse_history, GameRecord, LetterHint, MAX_GUESSES};
+
+const HISTORY_KEY: &str = "ywd-history";
+/// A pause after each row has flipped, before the next one is played back.
+const REPLAY_PAUSE_MS: u32 = 500;
+
+pub fn load_history() -> Vec<GameRecord> {
+    LocalStorage::get::<String>(HISTORY_KEY).map(|log| {parse_history(&log)}).unwrap_or_default()
+}
+
+/// Replaces the whole log, e.g. after merging in an import.
+pub fn save_history(records: &[GameRecord]) {
+    let log = records.iter().map(|record| {format!("{}\n", record)}).collect::<String>();
+    LocalStorage::set(HISTORY_KEY, log).ok();
+}
+
+/// Appends a finished game to the log.
+pub fn save_record(record: &GameRecord) {
+    let mut log = LocalStorage::get::<String>(HISTORY_KEY).unwrap_or_default();
+    log.push_str(&format!("{}\n", record));
+    LocalStorage::set(HISTORY_KEY, log).ok();
+}
+
+pub enum Msg {
+    Replay(usize),
+    Step,
+    Back,
+}
+
+pub struct History {
+    /// The record being replayed, and how many of its rows have been played back.
+    replaying: Option<(usize, usize)>,
+    _ticker: Option<Interval>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct HistoryProperties {
+    pub records: Vec<GameRecord>,
+    pub on_close: Callback<()>,
+}
+
+impl Component for History {
+    type Message = Msg;
+    type Properties = HistoryProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            replaying: None,
+            _ticker: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Replay(i) => {
+                let width = ctx.props().records[i].guesses.first().map_or(5, |guess| {guess.chars().count()}) as u32;
+                let link = ctx.link().clone();
+                // Each row gets the time the game took to reveal it.
+                let row_millis = (width - 1) * REVEAL_STAGGER_MS + FLIP_MS + REPLAY_PAUSE_MS;
+                self._ticker = Some(Interval::new(row_millis, move || {link.send_message(Msg::Step)}));
+                self.replaying = Some((i, 0));
+            },
+            Msg::Step => match &mut self.replaying {
+                // One step past the last row lets a solved board do its jump.
+                Some((i, played)) if *played <= ctx.props().records[*i].turns() => *played += 1,
+                _ => self._ticker = None,
+            },
+            Msg::Back => {
+                self.replaying = None;
+                self._ticker = None;
+            },
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let strings = strings();
+        let props = ctx.props();
+        let content = match self.replaying {
+            Some((i, played)) => self.view_replay(ctx, &props.records[i], played),
+            None if props.records.is_empty() => html! {<p>{strings.history_empty}</p>},
+            None => self.view_list(ctx),
+        };
+        let on_close = props.on_close.reform(|_| ());
+        html! {
+            <div class={self.style()}>
+                <div class="modal">
+                    <h2>{strings.history_title}</h2>
+                    {content}
+                    <button onclick={on_close}>{strings.help_close}</button>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl History {
+    /// Newest first.
+    fn view_list(&self, ctx: &Context<Self>) -> Html {
+        let records = &ctx.props().records;
+        html! {
+            <ul>{
+                records.iter().enumerate().rev().map(|(i, record)| {
+                    let onclick = ctx.link().callback(move |_| Msg::Replay(i));
+                    let date = Date::new(&JsValue::from_f64(record.finished as f64))
+                        .to_locale_date_string("default", &JsValue::UNDEFINED);
+                    let puzzle = if record.puzzle == "-" { String::new() } else { format!(" {}", record.puzzle) };
+                    let result = if record.is_won() { record.turns().to_string() } else { "X".to_string() };
+                    html! {
+                        <li>
+                            <span>{format!("{}{}", mode_name(&record.mode), puzzle)}</span>
+                            <span class="date">{String::from(date)}</span>
+                            <span>{result}</span>
+                            <a onclick={onclick}>{strings().history_replay}</a>
+                        </li>
+                    }
+                }).collect::<Html>()
+            }</ul>
+        }
+    }
+
+    fn view_replay(&self, ctx: &Context<Self>, record: &GameRecord, played: usize) -> Html {
+        let on_back = ctx.link().callback(|_| Msg::Back);
+        let height = record.turns().max(MAX_GUESSES);
+        let done = played > record.turns();
+        html! {
+            <>
+                <div class="boards" style={format!("--board-height: {}px", if record.boards.len() > 1 { 140 } else { 260 })}>{
+                    (0..record.boards.len()).map(|board| {
+                        let played_rows = record.rows(board);
+                        let solved = played_rows.last().is_some_and(|row| {row.iter().all(|&(_, hint)| {hint == LetterHint::Correct})});
+                        let jump_row = if done && solved { Some(played_rows.len() - 1) } else { None };
+                        let width = played_rows.first().map_or(5, Vec::len);
+                        let mut rows = vec![vec![(' ', LetterState::Initial); width]; height];
+                        for (row, tiles) in rows.iter_mut().zip(played_rows) {
+                            *row = tiles.into_iter().map(|(c, hint)| {(c, LetterState::Hint(hint))}).collect();
+                        }
+                        html! {<Board rows={rows} jump_row={jump_row} playback={Some(played)} />}
+                    }).collect::<Html>()
+                }</div>
+                <a onclick={on_back}>{strings().history_back}</a>
+            </>
+        }
+    }
+}
+
+impl YieldStyle for History {
+    fn style_from(&self) -> StyleSource<'static> {
+        css!(r#"
+            position: fixed;
+            top: 0;
+            left: 0;
+            width: 100%;
+            height: 100%;
+            z-index: 3;
+            background-color: rgba(255, 255, 255, 0.5);
+            display: flex;
+            justify-content: center;
+            align-items: center;
+            .modal {
+                min-width: 300px;
+                max-width: 460px;
+                max-height: 90%;
+                overflow-y: auto;
+                padding: 16px 24px;
+                background-color: #fff;
+                border-radius: 8px;
+                box-shadow: 0 4px 23px 0 rgba(0, 0, 0, 0.2);
+            }
+            ul {
+                margin: 0;
+                padding: 0;
+                list-style: none;
+            }
+            li {
+                display: flex;
+                justify-content: space-between;
+                margin: 4px 0;
+                font-variant-numeric: tabular-nums;
+            }
+            .date {
+                color: #787c7e;
+            }
+            a {
+                cursor: pointer;
+                color: #6aaa64;
+                text-decoration: underline;
+            }
+            .boards {
+                display: flex;
+                flex-wrap: wrap;
+                justify-content: center;
+            }
+            button {
+                display: block;
+                margin: 16px auto 0;
+                padding: 8px 24px;
+                border: 0;
+                border-radius: 4px;
+                cursor: pointer;
+                font-family: inherit;
+                font-weight: bold;
+                color: #fff;
+                background-color: #6aaa64;
+            }
+        "#)
+    }
+}
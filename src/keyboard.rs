@@ -1,53 +1,39 @@
-use std::{fmt, iter};
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::fmt::Formatter;
-use std::rc::Rc;
-use std::str::FromStr;
+use std::iter;
 use stylist::{css, StyleSource, YieldStyle};
 use yew::prelude::*;
 use yew::{classes, Html, Properties};
-use crate::Key::Letter;
-use crate::wordle::LetterHint;
+use ywd_core::{Key, KeyStates, LetterHint};
+use ywd_core::Key::Letter;
 
-#[derive(Debug, Clone, PartialEq, Copy)]
-pub enum Key {
-    Letter(char),
-    Enter,
-    Backspace,
-}
+pub struct Keyboard;
 
-impl FromStr for Key {
-    type Err = ();
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Layout {
+    Letters,
+    Equations,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            _ if s.len() == 1 => match s.chars().nth(0) {
-                Some(c@'a' ..= 'z') => Ok(Key::Letter(c)),
-                _ => Err(()),
-            },
-            "Enter" => Ok(Key::Enter),
-            "Backspace" => Ok(Key::Backspace),
-            _ => Err(()),
+impl Layout {
+    fn rows(&self) -> [&'static str; 3] {
+        match self {
+            Self::Letters => ["qwertyuiop", "asdfghjkl", "zxcvbnm"],
+            Self::Equations => ["1234567890", "+-*/=", ""],
         }
     }
 }
 
-impl fmt::Display for Key {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", match self {
-            Self::Letter(l) => l.to_string(),
-            k => format!("{:?}", k),
-        })
+impl Default for Layout {
+    fn default() -> Self {
+        Self::Letters
     }
 }
 
-pub struct Keyboard;
-
 #[derive(Properties, PartialEq)]
 pub struct KeyboardProperties {
     pub on_key_pressed: Callback<Key>,
-    pub letter_states: Rc<RefCell<HashMap<char, Option<LetterHint>>>>,  // todo make it immutable
+    pub key_states: Vec<KeyStates>,
+    #[prop_or_default]
+    pub layout: Layout,
 }
 
 impl Component for Keyboard {
@@ -59,11 +45,12 @@ impl Component for Keyboard {
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        let [top, middle, bottom] = ctx.props().layout.rows();
         let rows: Vec<Vec<Key>> = vec![
-            "qwertyuiop".chars().map(Key::Letter).collect(),
-            "asdfghjkl".chars().map(Key::Letter).collect(),
+            top.chars().map(Key::Letter).collect(),
+            middle.chars().map(Key::Letter).collect(),
             iter::once(Key::Enter)
-                .chain("zxcvbnm".chars().map(Key::Letter))
+                .chain(bottom.chars().map(Key::Letter))
                 .chain(iter::once(Key::Backspace)).collect(),
         ];
         html! {
@@ -73,13 +60,13 @@ impl Component for Keyboard {
                         <div class="row">{
                             iter::once(self.view_spacer(i == 1)).chain(row.iter().map(|&key| {
                                 let on_key_pressed = ctx.props().on_key_pressed.clone();
-                                let state = match &key {
-                                    Key::Letter(c) =>
-                                        (*ctx.props().letter_states).borrow().get(c)
-                                            .and_then(|s| {s.as_ref()}).map(|h| {h.clone()}),
-                                    _ => None,
+                                let states = match &key {
+                                    Key::Letter(c) => ctx.props().key_states.iter()
+                                        .map(|states| {states.get(*c)})
+                                        .collect(),
+                                    _ => vec![],
                                 };
-                                self.view_button(key.clone(), state, on_key_pressed)
+                                self.view_button(key.clone(), states, on_key_pressed)
                             })).chain(iter::once(self.view_spacer(i == 1))).collect::<Html>()
                         }</div>
                     }
@@ -101,19 +88,38 @@ impl Keyboard {
         }
     }
 
-    fn view_button(&self, key: Key, state: Option<LetterHint>, press_callback: Callback<Key>) -> Html {
+    fn view_button(&self, key: Key, states: Vec<Option<LetterHint>>, press_callback: Callback<Key>) -> Html {
         let big_key_class = if let Letter(_) = key { None } else { Some("big") };
-        let state_class = state.map(|s| {format!("{:?}", s)});
+        let state_class = match states.as_slice() {
+            [state] => state.map(|s| {format!("{:?}", s)}),
+            _ => None,
+        };
         html! {
             <button class={classes!(big_key_class, state_class)}
-                onclick={Callback::from(move |_| press_callback.emit(key))}>{
-                match key {
+                onclick={Callback::from(move |_| press_callback.emit(key))}>
+                {self.view_quadrants(&states)}
+                {match key {
                     Key::Backspace => self.view_backspace(),
                     key => html! {
                         <span>{format!("{}", key)}</span>
                     }
-                }
-            }</button>
+                }}
+            </button>
+        }
+    }
+
+    fn view_quadrants(&self, states: &[Option<LetterHint>]) -> Html {
+        if states.len() < 2 {
+            return html! {};
+        }
+        let columns = if states.len() > 4 { 4 } else { 2 };
+        html! {
+            <div class="quadrants" style={format!("grid-template-columns: repeat({}, 1fr)", columns)}>{
+                states.iter().map(|state| {
+                    let state_class = state.map(|s| {format!("{:?}", s)});
+                    html! {<div class={classes!(state_class)} />}
+                }).collect::<Html>()
+            }</div>
         }
     }
 
@@ -158,7 +164,31 @@ impl YieldStyle for Keyboard {
                align-items: center;
                text-transform: uppercase;
                -webkit-tap-highlight-color: rgba(0, 0, 0, 0.3);
-               transition: all 0.2s 1.5s;
+               transition: all 0.2s;
+               position: relative;
+           }
+           button span,
+           button svg {
+               position: relative;
+           }
+           .quadrants {
+               position: absolute;
+               top: 0;
+               left: 0;
+               width: 100%;
+               height: 100%;
+               display: grid;
+               border-radius: 4px;
+               overflow: hidden;
+           }
+           .quadrants .Correct {
+               background-color: #6aaa64;
+           }
+           .quadrants .Present {
+               background-color: #c9b458;
+           }
+           .quadrants .Absent {
+               background-color: #787c7e;
            }
            button:last-of-type {
                margin: 0;
@@ -166,6 +196,20 @@ impl YieldStyle for Keyboard {
            button.big {
                flex: 1.5;
            }
+           button.Correct,
+           button.Present,
+           button.Absent {
+               color: #fff;
+           }
+           button.Correct {
+               background-color: #6aaa64;
+           }
+           button.Present {
+               background-color: #c9b458;
+           }
+           button.Absent {
+               background-color: #787c7e;
+           }
         "#)
     }
 }
@@ -0,0 +1,290 @@
+use gloo::storage::{LocalStorage, Storage};
+use gloo::utils::window;
+use stylist::{css, StyleSource, YieldStyle};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{HtmlInputElement, RequestInit, Response};
+use yew::prelude::*;
+use yew::{Html, Properties};
+use crate::race::{player_name, set_player_name};
+use crate::strings::strings;
+use ywd_core::{format_duration, rank_scores, Score, MAX_GUESSES};
+
+/// The score API to post to, e.g. `http://localhost:9002`. Nothing is sent until one is set.
+const LEADERBOARD_KEY: &str = "ywd-leaderboard";
+/// A daily score finished before the player picked a name, posted once they do.
+const PENDING_KEY: &str = "ywd-pending-score";
+/// Stands in for the name in a score that is waiting for one.
+const PENDING_PLAYER: &str = "-";
+/// The last puzzle a score was handed in for, so each daily is only posted once.
+const SUBMITTED_KEY: &str = "ywd-submitted";
+
+fn endpoint() -> Option<String> {
+    LocalStorage::get::<String>(LEADERBOARD_KEY).ok()
+        .map(|url| {url.trim_end_matches('/').to_string()})
+}
+
+/// Points the game at a score API, or stops posting when `url` is empty.
+fn set_endpoint(url: &str) -> bool {
+    match url.trim() {
+        "" => LocalStorage::delete(LEADERBOARD_KEY),
+        url if url.starts_with("http://") || url.starts_with("https://") => {
+            return LocalStorage::set(LEADERBOARD_KEY, url).is_ok();
+        },
+        _ => return false,
+    }
+    true
+}
+
+async fn post_score(endpoint: &str, score: &Score) {
+    let mut init = RequestInit::new();
+    init.method("POST").body(Some(&JsValue::from_str(&score.to_string())));
+    JsFuture::from(window().fetch_with_str_and_init(&format!("{}/scores", endpoint), &init)).await.ok();
+}
+
+/// Posts a daily score under the player's name, or keeps it until they have picked one. A puzzle that
+/// already had its score handed in is left alone.
+pub fn submit_score(score: Score) {
+    if LocalStorage::get::<i64>(SUBMITTED_KEY).ok() == Some(score.puzzle) {
+        return;
+    }
+    if let Some(endpoint) = endpoint() {
+        LocalStorage::set(SUBMITTED_KEY, score.puzzle).ok();
+        match player_name() {
+            Some(player) => spawn_local(async move {post_score(&endpoint, &Score { player, ..score }).await}),
+            None => {
+                LocalStorage::set(PENDING_KEY, Score { player: PENDING_PLAYER.to_string(), ..score }.to_string()).ok();
+            },
+        }
+    }
+}
+
+/// The score that is waiting for a name, taken out of storage.
+fn take_pending_score() -> Option<Score> {
+    let score = LocalStorage::get::<String>(PENDING_KEY).ok().and_then(|line| {line.parse().ok()});
+    LocalStorage::delete(PENDING_KEY);
+    score
+}
+
+async fn fetch_scores(endpoint: String, puzzle: i64) -> Result<Vec<Score>, JsValue> {
+    let response = JsFuture::from(window().fetch_with_str(&format!("{}/scores/{}", endpoint, puzzle))).await?
+        .dyn_into::<Response>()?;
+    let text = JsFuture::from(response.text()?).await?.as_string().unwrap_or_default();
+    let mut scores = text.lines().filter_map(|line| {line.parse().ok()}).collect::<Vec<Score>>();
+    rank_scores(&mut scores);
+    Ok(scores)
+}
+
+pub enum Msg {
+    Loaded(Option<Vec<Score>>),
+    EditName(String),
+    SaveName,
+    EditEndpoint(String),
+    SaveEndpoint,
+}
+
+pub struct Leaderboard {
+    /// `None` while loading, then `None` inside for a failed request.
+    scores: Option<Option<Vec<Score>>>,
+    /// The name being typed, for a player who hasn't picked one yet.
+    name: String,
+    name_invalid: bool,
+    /// The server address being typed.
+    endpoint: String,
+    endpoint_invalid: bool,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct LeaderboardProperties {
+    pub puzzle: i64,
+}
+
+impl Component for Leaderboard {
+    type Message = Msg;
+    type Properties = LeaderboardProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let leaderboard = Self {
+            scores: None,
+            name: String::new(),
+            name_invalid: false,
+            endpoint: endpoint().unwrap_or_default(),
+            endpoint_invalid: false,
+        };
+        leaderboard.load(ctx);
+        leaderboard
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Loaded(scores) => {
+                self.scores = Some(scores);
+                true
+            },
+            Msg::EditName(name) => {
+                self.name = name;
+                self.name_invalid = false;
+                true
+            },
+            Msg::SaveName => {
+                let name = self.name.trim();
+                self.name_invalid = !set_player_name(name);
+                if let (false, Some(endpoint)) = (self.name_invalid, endpoint()) {
+                    let pending = take_pending_score().map(|score| {Score { player: name.to_string(), ..score }});
+                    let link = ctx.link().clone();
+                    let puzzle = ctx.props().puzzle;
+                    self.scores = None;
+                    // The board is fetched again once the waiting score is in, so it shows up straight away.
+                    spawn_local(async move {
+                        if let Some(score) = pending {
+                            post_score(&endpoint, &score).await;
+                        }
+                        link.send_message(Msg::Loaded(fetch_scores(endpoint, puzzle).await.ok()));
+                    });
+                }
+                true
+            },
+            Msg::EditEndpoint(url) => {
+                self.endpoint = url;
+                self.endpoint_invalid = false;
+                true
+            },
+            Msg::SaveEndpoint => {
+                self.endpoint_invalid = !set_endpoint(&self.endpoint);
+                if !self.endpoint_invalid {
+                    self.scores = None;
+                    self.load(ctx);
+                }
+                true
+            },
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let strings = strings();
+        if endpoint().is_none() {
+            return html! {<div class={self.style()}>{self.view_server(ctx)}</div>};
+        }
+        let body = match &self.scores {
+            None => html! {<p>{strings.leaderboard_loading}</p>},
+            Some(None) => html! {<p>{strings.leaderboard_failed}</p>},
+            Some(Some(scores)) if scores.is_empty() => html! {<p>{strings.leaderboard_empty}</p>},
+            Some(Some(scores)) => html! {
+                <ol>{
+                    scores.iter().map(|score| {
+                        let guesses = if score.is_won() { score.rows.len().to_string() } else { "X".to_string() };
+                        html! {
+                            <li>
+                                <span class="player">{&score.player}</span>
+                                {format!("{}/{}", guesses, MAX_GUESSES)}
+                                {if score.hard_mode { "*" } else { "" }}
+                                <span class="hints">{if score.hints > 0 { format!("+{}💡", score.hints) } else { String::new() }}</span>
+                                <span class="time">{format_duration(score.millis)}</span>
+                            </li>
+                        }
+                    }).collect::<Html>()
+                }</ol>
+            },
+        };
+        html! {
+            <div class={self.style()}>
+                <h3>{strings.leaderboard_title}</h3>
+                {self.view_name(ctx)}
+                {body}
+                {self.view_server(ctx)}
+            </div>
+        }
+    }
+}
+
+impl Leaderboard {
+    fn load(&self, ctx: &Context<Self>) {
+        if let Some(endpoint) = endpoint() {
+            let link = ctx.link().clone();
+            let puzzle = ctx.props().puzzle;
+            spawn_local(async move {
+                link.send_message(Msg::Loaded(fetch_scores(endpoint, puzzle).await.ok()));
+            });
+        }
+    }
+
+    /// Where scores are posted. Nothing is sent until an address is saved here.
+    fn view_server(&self, ctx: &Context<Self>) -> Html {
+        let strings = strings();
+        let oninput = ctx.link().callback(|e: InputEvent| {Msg::EditEndpoint(e.target_unchecked_into::<HtmlInputElement>().value())});
+        let onsubmit = ctx.link().callback(|e: FocusEvent| {
+            e.prevent_default();
+            Msg::SaveEndpoint
+        });
+        html! {
+            <details class="server">
+                <summary>{strings.leaderboard_server}</summary>
+                <form onsubmit={onsubmit}>
+                    <input type="url" placeholder="https://" value={self.endpoint.clone()} oninput={oninput} />
+                    <input type="submit" value={strings.leaderboard_save} />
+                    {if self.endpoint_invalid { html! {<p class="invalid">{strings.leaderboard_server_invalid}</p>} } else { html! {} }}
+                </form>
+            </details>
+        }
+    }
+
+    /// Asks for a name until the player has one, since scores are only posted under a name they picked.
+    fn view_name(&self, ctx: &Context<Self>) -> Html {
+        if player_name().is_some() {
+            return html! {};
+        }
+        let strings = strings();
+        let oninput = ctx.link().callback(|e: InputEvent| {Msg::EditName(e.target_unchecked_into::<HtmlInputElement>().value())});
+        let onsubmit = ctx.link().callback(|e: FocusEvent| {
+            e.prevent_default();
+            Msg::SaveName
+        });
+        html! {
+            <form class="name" onsubmit={onsubmit}>
+                <p>{strings.leaderboard_name_prompt}</p>
+                <input value={self.name.clone()} maxlength="24" oninput={oninput} />
+                <input type="submit" value={strings.leaderboard_save} />
+                {if self.name_invalid { html! {<p class="invalid">{strings.leaderboard_name_invalid}</p>} } else { html! {} }}
+            </form>
+        }
+    }
+}
+
+impl YieldStyle for Leaderboard {
+    fn style_from(&self) -> StyleSource<'static> {
+        css!(r#"
+            ol {
+                margin: 0;
+                padding-left: 24px;
+                text-align: left;
+            }
+            li {
+                display: flex;
+                margin: 2px 0;
+                font-variant-numeric: tabular-nums;
+            }
+            .player {
+                flex: 1;
+                font-weight: bold;
+            }
+            .hints {
+                margin-left: 4px;
+            }
+            .time {
+                width: 64px;
+                text-align: right;
+            }
+            .name p {
+                margin: 4px 0;
+            }
+            .invalid {
+                color: #c9b458;
+            }
+            .server {
+                margin-top: 8px;
+                font-size: 12px;
+                text-align: left;
+            }
+        "#)
+    }
+}
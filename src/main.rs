@@ -1,18 +1,27 @@
-#![feature(array_zip)]
-#![feature(if_let_guard)]
-
 mod keyboard;
-mod wordle;
+mod backup;
 mod game;
+mod help;
+mod history;
+mod leaderboard;
+mod race;
+mod result;
+mod route;
+mod statistics;
+mod strings;
+mod board;
+mod row;
+mod tile;
 
 use gloo::events::EventListener;
 use gloo::utils::{document, window};
 use stylist::{global_style, GlobalStyle};
 use yew::prelude::*;
+use yew_router::prelude::*;
 
 use keyboard::Keyboard;
-use crate::keyboard::Key;
 use game::Game;
+use route::Route;
 
 struct App;
 
@@ -26,13 +35,17 @@ impl Component for App {
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         html! {
-            <>
-                <Game />
-            </>
+            <BrowserRouter>
+                <Switch<Route> render={Switch::render(switch)} />
+            </BrowserRouter>
         }
     }
 }
 
+fn switch(route: &Route) -> Html {
+    html! {<Game route={route.clone()} />}
+}
+
 fn style() -> GlobalStyle {
     global_style!(r#"
         body {
@@ -0,0 +1,129 @@
+use gloo::events::EventListener;
+use gloo::storage::{LocalStorage, Storage};
+use wasm_bindgen::JsCast;
+use web_sys::{MessageEvent, WebSocket};
+use yew::Callback;
+use ywd_core::{is_valid_name, Clock, ClientMessage, LetterHint, RaceResult, ServerMessage, SystemClock};
+
+const NAME_KEY: &str = "ywd-name";
+/// Overrides the relay address, for racing across machines.
+const RELAY_KEY: &str = "ywd-relay";
+const DEFAULT_RELAY: &str = "ws://localhost:9001";
+
+/// Another player in the room, known only by the colours of their guesses.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Opponent {
+    pub name: String,
+    pub rows: Vec<Vec<LetterHint>>,
+    pub result: Option<RaceResult>,
+    pub left: bool,
+}
+
+/// A connection to the relay that sends `join` as soon as it opens.
+struct RaceClient {
+    socket: WebSocket,
+    _listeners: Vec<EventListener>,
+}
+
+impl RaceClient {
+    fn connect(join: &ClientMessage, on_message: Callback<ServerMessage>, on_close: Callback<()>) -> Option<Self> {
+        let url = LocalStorage::get::<String>(RELAY_KEY).unwrap_or_else(|_| {DEFAULT_RELAY.to_string()});
+        let socket = WebSocket::new(&url).ok()?;
+        let join = join.to_string();
+        let open_socket = socket.clone();
+        let listeners = vec![
+            EventListener::new(&socket, "open", move |_| {
+                open_socket.send_with_str(&join).ok();
+            }),
+            EventListener::new(&socket, "message", move |e| {
+                let message = e.dyn_ref::<MessageEvent>()
+                    .and_then(|e| {e.data().as_string()})
+                    .and_then(|text| {text.parse().ok()});
+                if let Some(message) = message {
+                    on_message.emit(message);
+                }
+            }),
+            EventListener::new(&socket, "close", move |_| {on_close.emit(())}),
+        ];
+        Some(Self { socket, _listeners: listeners })
+    }
+
+    fn send(&self, message: &ClientMessage) {
+        self.socket.send_with_str(&message.to_string()).ok();
+    }
+}
+
+impl Drop for RaceClient {
+    fn drop(&mut self) {
+        // The listeners go right after this, so leaving on purpose doesn't report a lost connection.
+        self.socket.close().ok();
+    }
+}
+
+/// The name this browser's player picked, if they have.
+pub fn player_name() -> Option<String> {
+    LocalStorage::get::<String>(NAME_KEY).ok().filter(|name| {is_valid_name(name)})
+}
+
+/// Remembers the player's name, if the relay and leaderboard would take it.
+pub fn set_player_name(name: &str) -> bool {
+    is_valid_name(name) && LocalStorage::set(NAME_KEY, name).is_ok()
+}
+
+/// One player's side of a race: the connection plus what is known about everybody else.
+pub struct Race {
+    pub name: String,
+    /// The shared answer, once the relay has sent it.
+    pub seed: Option<u64>,
+    pub opponents: Vec<Opponent>,
+    client: RaceClient,
+}
+
+impl Race {
+    pub fn join(room: &str, on_message: Callback<ServerMessage>, on_close: Callback<()>) -> Option<Self> {
+        // Racing doesn't need a name of the player's own, so anyone who hasn't picked one races as a guest.
+        let name = player_name().unwrap_or_else(|| {format!("guest-{}", SystemClock.now() % 10_000)});
+        let join = ClientMessage::Join { room: room.to_string(), name: name.clone() };
+        Some(Self {
+            client: RaceClient::connect(&join, on_message, on_close)?,
+            name,
+            seed: None,
+            opponents: vec![],
+        })
+    }
+
+    pub fn send(&self, message: &ClientMessage) {
+        self.client.send(message);
+    }
+
+    /// Keeps track of the other players. The relay echoes this player's own progress, which is ignored.
+    pub fn receive(&mut self, message: &ServerMessage) {
+        match message {
+            ServerMessage::Seed(seed) => self.seed = Some(*seed),
+            ServerMessage::Joined(name) => {
+                self.opponents.retain(|opponent| {opponent.name != *name});
+                self.opponents.push(Opponent { name: name.clone(), rows: vec![], result: None, left: false });
+            },
+            ServerMessage::Left(name) => {
+                if let Some(opponent) = self.opponent_mut(name) {
+                    opponent.left = true;
+                }
+            },
+            ServerMessage::Progress { name, hints } => {
+                if let Some(opponent) = self.opponent_mut(name) {
+                    opponent.rows.push(hints.clone());
+                }
+            },
+            ServerMessage::Finished(result) => {
+                if let Some(opponent) = self.opponent_mut(&result.name) {
+                    opponent.result = Some(result.clone());
+                }
+            },
+            ServerMessage::Winner(_) => (),
+        }
+    }
+
+    fn opponent_mut(&mut self, name: &str) -> Option<&mut Opponent> {
+        self.opponents.iter_mut().find(|opponent| {opponent.name == name})
+    }
+}
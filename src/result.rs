@@ -0,0 +1,100 @@
+use stylist::{css, StyleSource, YieldStyle};
+use yew::prelude::*;
+use yew::{Html, Properties};
+use crate::strings::strings;
+
+pub struct GameResult;
+
+#[derive(Properties, PartialEq)]
+pub struct GameResultProperties {
+    pub lines: Vec<String>,
+    pub share: String,
+    pub on_close: Callback<()>,
+    /// Shown between the lines and the share text, e.g. boards to compare.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+impl Component for GameResult {
+    type Message = ();
+    type Properties = GameResultProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let strings = strings();
+        let props = ctx.props();
+        let on_close = props.on_close.reform(|_| ());
+        html! {
+            <div class={self.style()}>
+                <div class="modal">
+                    <h2>{strings.result_title}</h2>
+                    {props.lines.iter().map(|line| html! {<p>{line}</p>}).collect::<Html>()}
+                    {for props.children.iter()}
+                    <h3>{strings.result_share}</h3>
+                    <textarea readonly=true rows={(props.share.lines().count() + 1).to_string()} value={props.share.clone()} />
+                    <button onclick={on_close}>{strings.help_close}</button>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl YieldStyle for GameResult {
+    fn style_from(&self) -> StyleSource<'static> {
+        css!(r#"
+            position: fixed;
+            top: 0;
+            left: 0;
+            width: 100%;
+            height: 100%;
+            z-index: 3;
+            background-color: rgba(255, 255, 255, 0.5);
+            display: flex;
+            justify-content: center;
+            align-items: center;
+            .modal {
+                min-width: 260px;
+                max-width: 460px;
+                max-height: 90%;
+                overflow-y: auto;
+                padding: 16px 24px;
+                background-color: #fff;
+                border-radius: 8px;
+                box-shadow: 0 4px 23px 0 rgba(0, 0, 0, 0.2);
+            }
+            .boards {
+                display: flex;
+                justify-content: center;
+                --board-height: 200px;
+            }
+            figure {
+                margin: 0 4px;
+            }
+            p {
+                margin: 4px 0;
+                font-variant-numeric: tabular-nums;
+            }
+            textarea {
+                width: 100%;
+                box-sizing: border-box;
+                resize: none;
+                font-family: inherit;
+            }
+            button {
+                display: block;
+                margin: 16px auto 0;
+                padding: 8px 24px;
+                border: 0;
+                border-radius: 4px;
+                cursor: pointer;
+                font-family: inherit;
+                font-weight: bold;
+                color: #fff;
+                background-color: #6aaa64;
+            }
+        "#)
+    }
+}
@@ -0,0 +1,44 @@
+use yew_router::prelude::*;
+
+#[derive(Clone, Routable, PartialEq, Debug)]
+pub enum Route {
+    #[at("/")]
+    Home,
+    #[at("/daily")]
+    Daily,
+    #[at("/practice")]
+    Practice,
+    #[at("/archive/:n")]
+    Archive { n: i64 },
+    #[at("/challenge/:code")]
+    Challenge { code: String },
+    #[at("/stats")]
+    Stats,
+    #[at("/history")]
+    History,
+    #[at("/absurd")]
+    Adversarial,
+    #[at("/dordle")]
+    Dordle,
+    #[at("/quordle")]
+    Quordle,
+    #[at("/octordle")]
+    Octordle,
+    #[at("/nerdle")]
+    Nerdle,
+    #[at("/timed")]
+    Timed,
+    #[at("/blitz")]
+    Blitz,
+    #[at("/race/:room")]
+    Race { room: String },
+    #[at("/versus")]
+    Versus,
+    #[at("/versus/:code")]
+    VersusChallenge { code: String },
+    #[at("/versus/:code/:result")]
+    VersusReply { code: String, result: String },
+    #[not_found]
+    #[at("/404")]
+    NotFound,
+}
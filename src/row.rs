@@ -0,0 +1,99 @@
+use stylist::{css, StyleSource, YieldStyle};
+use yew::prelude::*;
+use yew::{classes, Html, Properties};
+use crate::tile::{LetterState, Tile, REVEAL_STAGGER_MS};
+
+pub struct Row;
+
+#[derive(Properties, PartialEq)]
+pub struct RowProperties {
+    pub tiles: Vec<(char, LetterState)>,
+    #[prop_or_default]
+    pub shake: bool,
+    #[prop_or_default]
+    pub jump: bool,
+    #[prop_or_default]
+    pub cursor: Option<usize>,
+    #[prop_or_default]
+    pub on_tile_clicked: Option<Callback<usize>>,
+}
+
+impl Component for Row {
+    type Message = ();
+    type Properties = RowProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let shake_class = if props.shake { Some("shake") } else { None };
+        html! {
+            <div class={classes!(self.style(), "row", shake_class)}
+                style={format!("grid-template-columns: repeat({}, 1fr)", props.tiles.len())}>{
+                props.tiles.iter().enumerate().map(|(index, &(letter, state))| {
+                    let on_click = props.on_tile_clicked.as_ref().map(|cb| {cb.reform(move |_: MouseEvent| index)});
+                    html! {
+                        <Tile
+                            letter={letter}
+                            state={state}
+                            reveal_delay={index as u32 * REVEAL_STAGGER_MS}
+                            jump={props.jump}
+                            jump_delay={index as u32 * 100}
+                            cursor={props.cursor == Some(index)}
+                            on_click={on_click} />
+                    }
+                }).collect::<Html>()
+            }</div>
+        }
+    }
+}
+
+impl YieldStyle for Row {
+    fn style_from(&self) -> StyleSource<'static> {
+        css!(r#"
+            display: grid;
+            grid-gap: 5px;
+
+            &.shake {
+                animation: shake 0.5s;
+            }
+            @keyframes shake {
+                0% {
+                    transform: translate(1px);
+                }
+                10% {
+                    transform: translate(-2px);
+                }
+                20% {
+                    transform: translate(2px);
+                }
+                30% {
+                    transform: translate(-2px);
+                }
+                40% {
+                    transform: translate(2px);
+                }
+                50% {
+                    transform: translate(-2px);
+                }
+                60% {
+                    transform: translate(2px);
+                }
+                70% {
+                    transform: translate(-2px);
+                }
+                80% {
+                    transform: translate(2px);
+                }
+                90% {
+                    transform: translate(-2px);
+                }
+                100% {
+                    transform: translate(1px);
+                }
+            }
+        "#)
+    }
+}
@@ -0,0 +1,182 @@
+use gloo::file::callbacks::{read_as_text, FileReader};
+use gloo::file::File;
+use stylist::{css, StyleSource, YieldStyle};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+use yew::{Html, Properties};
+use crate::leaderboard::Leaderboard;
+use crate::strings::strings;
+use ywd_core::{today_puzzle_number, Stats};
+
+pub enum Msg {
+    Import(Event),
+}
+
+pub struct Statistics {
+    /// Reading a file stops when its reader is dropped.
+    reader: Option<FileReader>,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct StatisticsProperties {
+    pub stats: Stats,
+    pub on_close: Callback<()>,
+    pub on_export: Callback<()>,
+    /// The text of the file picked for import, or `None` if it couldn't be read.
+    pub on_import: Callback<Option<String>>,
+}
+
+impl Component for Statistics {
+    type Message = Msg;
+    type Properties = StatisticsProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            reader: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::Import(e) => {
+                let input = match e.target().and_then(|target| {target.dyn_into::<HtmlInputElement>().ok()}) {
+                    Some(input) => input,
+                    None => return false,
+                };
+                if let Some(file) = input.files().and_then(|files| {files.get(0)}) {
+                    let on_import = ctx.props().on_import.clone();
+                    self.reader = Some(read_as_text(&File::from(file), move |text| {on_import.emit(text.ok())}));
+                }
+                // Picking the same file again should import it again.
+                input.set_value("");
+            },
+        }
+        false
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let strings = strings();
+        let stats = &ctx.props().stats;
+        let on_close = ctx.props().on_close.reform(|_| ());
+        let max = stats.distribution.iter().copied().max().unwrap_or(0).max(1);
+        html! {
+            <div class={self.style()}>
+                <div class="modal">
+                    <h2>{strings.stats_title}</h2>
+                    <div class="summary">
+                        {self.view_number(stats.played, strings.stats_played)}
+                        {self.view_number(stats.win_rate(), strings.stats_win_rate)}
+                        {self.view_number(stats.current_streak, strings.stats_current_streak)}
+                        {self.view_number(stats.max_streak, strings.stats_max_streak)}
+                    </div>
+                    <h3>{strings.stats_distribution}</h3>
+                    {stats.distribution.iter().enumerate().map(|(i, &count)| html! {
+                        <div class="bar">
+                            <span>{i + 1}</span>
+                            <div style={format!("width: {}%", 8 + count * 92 / max)}>{count}</div>
+                        </div>
+                    }).collect::<Html>()}
+                    <Leaderboard puzzle={today_puzzle_number()} />
+                    <div class="data">
+                        <a onclick={ctx.props().on_export.reform(|_| ())}>{strings.data_export}</a>
+                        <label>
+                            {strings.data_import}
+                            <input type="file" accept="application/json" onchange={ctx.link().callback(Msg::Import)} />
+                        </label>
+                    </div>
+                    <button onclick={on_close}>{strings.help_close}</button>
+                </div>
+            </div>
+        }
+    }
+}
+
+impl Statistics {
+    fn view_number(&self, value: u32, label: &str) -> Html {
+        html! {
+            <div>
+                <div class="value">{value}</div>
+                <div class="label">{label}</div>
+            </div>
+        }
+    }
+}
+
+impl YieldStyle for Statistics {
+    fn style_from(&self) -> StyleSource<'static> {
+        css!(r#"
+            position: fixed;
+            top: 0;
+            left: 0;
+            width: 100%;
+            height: 100%;
+            z-index: 3;
+            background-color: rgba(255, 255, 255, 0.5);
+            display: flex;
+            justify-content: center;
+            align-items: center;
+            .modal {
+                min-width: 300px;
+                max-width: 460px;
+                max-height: 90%;
+                overflow-y: auto;
+                padding: 16px 24px;
+                background-color: #fff;
+                border-radius: 8px;
+                box-shadow: 0 4px 23px 0 rgba(0, 0, 0, 0.2);
+            }
+            .summary {
+                display: flex;
+                justify-content: space-around;
+            }
+            .value {
+                font-size: 32px;
+            }
+            .label {
+                font-size: 12px;
+            }
+            .bar {
+                display: flex;
+                margin: 4px 0;
+                text-align: right;
+            }
+            .bar span {
+                width: 16px;
+                margin-right: 4px;
+            }
+            .bar div {
+                padding: 0 6px;
+                color: #fff;
+                font-weight: bold;
+                background-color: #787c7e;
+            }
+            .data {
+                display: flex;
+                justify-content: space-around;
+                margin-top: 16px;
+                font-size: 14px;
+            }
+            .data a, .data label {
+                cursor: pointer;
+                color: #6aaa64;
+                text-decoration: underline;
+            }
+            .data input {
+                display: none;
+            }
+            button {
+                display: block;
+                margin: 16px auto 0;
+                padding: 8px 24px;
+                border: 0;
+                border-radius: 4px;
+                cursor: pointer;
+                font-family: inherit;
+                font-weight: bold;
+                color: #fff;
+                background-color: #6aaa64;
+            }
+        "#)
+    }
+}
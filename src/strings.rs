@@ -0,0 +1,268 @@
+use gloo::utils::window;
+
+pub struct Strings {
+    pub not_enough_letters: &'static str,
+    pub not_in_word_list: &'static str,
+    pub win: &'static str,
+    pub words_remaining: &'static str,
+    pub mode_daily: &'static str,
+    pub mode_adversarial: &'static str,
+    pub mode_dordle: &'static str,
+    pub mode_quordle: &'static str,
+    pub mode_octordle: &'static str,
+    pub mode_nerdle: &'static str,
+    pub mode_timed: &'static str,
+    pub mode_blitz: &'static str,
+    pub time_up: &'static str,
+    pub result_title: &'static str,
+    pub result_time: &'static str,
+    pub result_guess: &'static str,
+    pub result_solved: &'static str,
+    pub result_share: &'static str,
+    pub hint_letter: &'static str,
+    pub hint_eliminate: &'static str,
+    pub hint_position: &'static str,
+    pub hint_removed: &'static str,
+    pub no_hints: &'static str,
+    pub paste_wrong_length: &'static str,
+    pub paste_invalid: &'static str,
+    pub mode_practice: &'static str,
+    pub mode_archive: &'static str,
+    pub mode_challenge: &'static str,
+    pub invalid_link: &'static str,
+    pub mode_race: &'static str,
+    pub race_waiting: &'static str,
+    pub race_joined: &'static str,
+    pub race_left: &'static str,
+    pub race_finished: &'static str,
+    pub race_winner: &'static str,
+    pub race_no_winner: &'static str,
+    pub race_disconnected: &'static str,
+    pub mode_versus: &'static str,
+    pub versus_player: &'static str,
+    pub versus_choose: &'static str,
+    pub versus_turn: &'static str,
+    pub versus_send_link: &'static str,
+    pub versus_send_reply: &'static str,
+    pub versus_you: &'static str,
+    pub versus_opponent: &'static str,
+    pub versus_draw: &'static str,
+    pub leaderboard_title: &'static str,
+    pub leaderboard_loading: &'static str,
+    pub leaderboard_failed: &'static str,
+    pub leaderboard_empty: &'static str,
+    pub leaderboard_name_prompt: &'static str,
+    pub leaderboard_save: &'static str,
+    pub leaderboard_name_invalid: &'static str,
+    pub leaderboard_server: &'static str,
+    pub leaderboard_server_invalid: &'static str,
+    pub stats_title: &'static str,
+    pub stats_played: &'static str,
+    pub stats_win_rate: &'static str,
+    pub stats_current_streak: &'static str,
+    pub stats_max_streak: &'static str,
+    pub stats_distribution: &'static str,
+    pub history_title: &'static str,
+    pub history_empty: &'static str,
+    pub history_replay: &'static str,
+    pub history_back: &'static str,
+    pub data_export: &'static str,
+    pub data_import: &'static str,
+    pub import_done: &'static str,
+    pub import_malformed: &'static str,
+    pub import_version: &'static str,
+    pub import_stats: &'static str,
+    pub import_record: &'static str,
+    pub update_available: &'static str,
+    pub update_reload: &'static str,
+    pub help_title: &'static str,
+    pub help_rules: [&'static str; 3],
+    pub help_examples: &'static str,
+    pub help_correct: &'static str,
+    pub help_present: &'static str,
+    pub help_absent: &'static str,
+    pub help_close: &'static str,
+}
+
+static EN: Strings = Strings {
+    not_enough_letters: "Not enough letters",
+    not_in_word_list: "Not in word list",
+    win: "Splendid!",
+    words_remaining: "words remaining",
+    mode_daily: "Daily",
+    mode_adversarial: "Absurd",
+    mode_dordle: "Dordle",
+    mode_quordle: "Quordle",
+    mode_octordle: "Octordle",
+    mode_nerdle: "Nerdle",
+    mode_timed: "Timed",
+    mode_blitz: "Blitz",
+    time_up: "Time's up!",
+    result_title: "Results",
+    result_time: "Time",
+    result_guess: "Guess",
+    result_solved: "Solved",
+    result_share: "Share",
+    hint_letter: "Reveal a letter",
+    hint_eliminate: "Remove letters",
+    hint_position: "Letter",
+    hint_removed: "Not in the word",
+    no_hints: "No more hints",
+    paste_wrong_length: "Pasted word has the wrong length",
+    paste_invalid: "Pasted text has invalid characters",
+    mode_practice: "Practice",
+    mode_archive: "Archive",
+    mode_challenge: "Challenge",
+    invalid_link: "Invalid link",
+    mode_race: "Race",
+    race_waiting: "Connecting to the race…",
+    race_joined: "joined",
+    race_left: "left",
+    race_finished: "finished",
+    race_winner: "wins!",
+    race_no_winner: "Nobody solved it",
+    race_disconnected: "Lost connection to the race",
+    mode_versus: "Versus",
+    versus_player: "Player",
+    versus_choose: "pick a word for your opponent",
+    versus_turn: "your turn",
+    versus_send_link: "Or send your opponent this link:",
+    versus_send_reply: "Send this link back to your opponent to compare results.",
+    versus_you: "You",
+    versus_opponent: "Opponent",
+    versus_draw: "It's a draw",
+    leaderboard_title: "Today's Leaderboard",
+    leaderboard_loading: "Loading…",
+    leaderboard_failed: "Couldn't reach the leaderboard",
+    leaderboard_empty: "No scores yet",
+    leaderboard_name_prompt: "Pick a name to post your daily scores under",
+    leaderboard_save: "Save",
+    leaderboard_name_invalid: "Use up to 24 letters, digits, - or _",
+    leaderboard_server: "Leaderboard server",
+    leaderboard_server_invalid: "Use an http:// or https:// address, or leave it empty to stop posting",
+    stats_title: "Statistics",
+    stats_played: "Played",
+    stats_win_rate: "Win %",
+    stats_current_streak: "Current Streak",
+    stats_max_streak: "Max Streak",
+    stats_distribution: "Guess Distribution",
+    history_title: "History",
+    history_empty: "No finished games yet",
+    history_replay: "Replay",
+    history_back: "Back to the list",
+    data_export: "Export data",
+    data_import: "Import data",
+    import_done: "Stats and history imported",
+    import_malformed: "That isn't a YDW export",
+    import_version: "That export is from a newer version of YDW",
+    import_stats: "The stats in that export are damaged",
+    import_record: "That export has a damaged game:",
+    update_available: "A new version is available.",
+    update_reload: "Reload",
+    help_title: "How to play",
+    help_rules: [
+        "Guess the word in 6 tries.",
+        "Each guess must be a valid 5-letter word. Hit the enter button to submit.",
+        "After each guess, the color of the tiles will change to show how close your guess was to the word.",
+    ],
+    help_examples: "Examples",
+    help_correct: "is in the word and in the correct spot.",
+    help_present: "is in the word but in the wrong spot.",
+    help_absent: "is not in the word in any spot.",
+    help_close: "Got it",
+};
+
+static ZH: Strings = Strings {
+    not_enough_letters: "字母数量不足",
+    not_in_word_list: "不在词库中",
+    win: "太棒了！",
+    words_remaining: "个候选词",
+    mode_daily: "每日",
+    mode_adversarial: "刁难",
+    mode_dordle: "双词",
+    mode_quordle: "四词",
+    mode_octordle: "八词",
+    mode_nerdle: "算式",
+    mode_timed: "计时",
+    mode_blitz: "闪电",
+    time_up: "时间到！",
+    result_title: "成绩",
+    result_time: "用时",
+    result_guess: "猜测",
+    result_solved: "解出",
+    result_share: "分享",
+    hint_letter: "揭示字母",
+    hint_eliminate: "排除字母",
+    hint_position: "位置",
+    hint_removed: "不在单词中",
+    no_hints: "没有更多提示",
+    paste_wrong_length: "粘贴的单词长度不对",
+    paste_invalid: "粘贴的内容包含无效字符",
+    mode_practice: "练习",
+    mode_archive: "往期",
+    mode_challenge: "挑战",
+    invalid_link: "链接无效",
+    mode_race: "竞速",
+    race_waiting: "正在连接比赛…",
+    race_joined: "加入了",
+    race_left: "离开了",
+    race_finished: "完成了",
+    race_winner: "获胜！",
+    race_no_winner: "没有人猜出",
+    race_disconnected: "与比赛的连接已断开",
+    mode_versus: "对战",
+    versus_player: "玩家",
+    versus_choose: "为对手选一个单词",
+    versus_turn: "轮到你了",
+    versus_send_link: "或者把这个链接发给对手：",
+    versus_send_reply: "把这个链接发回给对手来比较成绩。",
+    versus_you: "你",
+    versus_opponent: "对手",
+    versus_draw: "平局",
+    leaderboard_title: "今日排行榜",
+    leaderboard_loading: "加载中…",
+    leaderboard_failed: "无法连接排行榜",
+    leaderboard_empty: "还没有成绩",
+    leaderboard_name_prompt: "起一个名字来提交每日成绩",
+    leaderboard_save: "保存",
+    leaderboard_name_invalid: "最多 24 个字母、数字、- 或 _",
+    leaderboard_server: "排行榜服务器",
+    leaderboard_server_invalid: "请使用 http:// 或 https:// 地址，留空则不再提交",
+    stats_title: "统计",
+    stats_played: "已玩",
+    stats_win_rate: "胜率",
+    stats_current_streak: "当前连胜",
+    stats_max_streak: "最高连胜",
+    stats_distribution: "猜测次数分布",
+    history_title: "历史记录",
+    history_empty: "还没有完成的游戏",
+    history_replay: "回放",
+    history_back: "返回列表",
+    data_export: "导出数据",
+    data_import: "导入数据",
+    import_done: "统计和历史记录已导入",
+    import_malformed: "这不是 YDW 的导出文件",
+    import_version: "这个导出文件来自更新版本的 YDW",
+    import_stats: "导出文件中的统计已损坏",
+    import_record: "导出文件中有损坏的游戏：",
+    update_available: "有新版本可用。",
+    update_reload: "刷新",
+    help_title: "玩法说明",
+    help_rules: [
+        "在 6 次之内猜出单词。",
+        "每次猜测必须是一个有效的 5 字母单词，按回车键提交。",
+        "每次猜测后，方块的颜色会改变，提示你的猜测与答案的接近程度。",
+    ],
+    help_examples: "示例",
+    help_correct: "在单词中且位置正确。",
+    help_present: "在单词中但位置错误。",
+    help_absent: "不在单词中。",
+    help_close: "知道了",
+};
+
+pub fn strings() -> &'static Strings {
+    match window().navigator().language() {
+        Some(lang) if lang.starts_with("zh") => &ZH,
+        _ => &EN,
+    }
+}
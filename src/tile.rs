@@ -0,0 +1,182 @@
+use std::fmt;
+use std::fmt::Formatter;
+use stylist::{css, StyleSource, YieldStyle};
+use yew::prelude::*;
+use yew::{classes, Html, Properties};
+use ywd_core::LetterHint;
+
+pub const REVEAL_STAGGER_MS: u32 = 300;
+pub const FLIP_MS: u32 = 600;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum LetterState {
+    Initial,
+    Hint(LetterHint),
+}
+
+impl fmt::Display for LetterState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Self::Hint(hint) => format!("{:?}", hint),
+            s => format!("{:?}", s),
+        })
+    }
+}
+
+pub struct Tile;
+
+#[derive(Properties, PartialEq)]
+pub struct TileProperties {
+    pub letter: char,
+    pub state: LetterState,
+    #[prop_or_default]
+    pub reveal_delay: u32,
+    #[prop_or_default]
+    pub jump: bool,
+    #[prop_or_default]
+    pub jump_delay: u32,
+    #[prop_or_default]
+    pub cursor: bool,
+    #[prop_or_default]
+    pub on_click: Option<Callback<MouseEvent>>,
+}
+
+impl Component for Tile {
+    type Message = ();
+    type Properties = TileProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let props = ctx.props();
+        let filled_class = if props.letter == ' ' { None } else { Some("filled") };
+        let revealed_class = if let LetterState::Initial = props.state {
+            None
+        } else { Some("revealed") };
+        let jump_class = if props.jump { Some("jump") } else { None };
+        let cursor_class = if props.cursor { Some("cursor") } else { None };
+        let editable_class = props.on_click.as_ref().map(|_| "editable");
+        html! {
+            <div
+                class={classes!(self.style(), "tile", filled_class, revealed_class, jump_class, cursor_class, editable_class)}
+                onclick={props.on_click.clone()}>
+                <div class="front" style={format!("transition-delay: {}ms", props.reveal_delay)}>
+                    {props.letter}
+                </div>
+                <div
+                    class={classes!("back", props.state.to_string())}
+                    style={format!("transition-delay: {}ms; animation-delay: {}ms", props.reveal_delay, props.jump_delay)}>
+                    {props.letter}
+                </div>
+            </div>
+        }
+    }
+}
+
+impl YieldStyle for Tile {
+    fn style_from(&self) -> StyleSource<'static> {
+        css!(r#"
+            width: 100%;
+            font-size: 2rem;
+            line-height: 2rem;
+            font-weight: bold;
+            vertical-align: middle;
+            text-transform: uppercase;
+            user-select: none;
+            position: relative;
+
+            &.filled {
+                animation: zoom 0.2s;
+            }
+            .front,
+            .back {
+                box-sizing: border-box;
+                display: inline-flex;
+                justify-content: center;
+                align-items: center;
+                position: absolute;
+                top: 0;
+                left: 0;
+                width: 100%;
+                height: 100%;
+                transition: transform 0.6s;
+                backface-visibility: hidden;
+                -webkit-backface-visibility: hidden;
+            }
+            .front {
+                border: 2px solid #d3d6da;
+            }
+            &.filled .front {
+                border-color: #999;
+            }
+            &.editable {
+                cursor: pointer;
+            }
+            &.cursor .front {
+                border-color: #1a1a1b;
+                border-bottom-width: 4px;
+            }
+            .back {
+                transform: rotateX(180deg);
+            }
+            &.revealed .front {
+                transform: rotateX(180deg);
+            }
+            &.revealed .back {
+                transform: rotateX(0deg);
+            }
+            &.jump .back {
+                animation: jump 0.5s;
+            }
+
+            .Correct,
+            .Present,
+            .Absent {
+                color: #fff;
+            }
+            .Correct {
+                background-color: #6aaa64;
+            }
+            .Present {
+                background-color: #c9b458;
+            }
+            .Absent {
+                background-color: #787c7e;
+            }
+
+            @keyframes zoom {
+                0% {
+                    transform: scale(1.1);
+                }
+                100% {
+                    transform: scale(1);
+                }
+            }
+            @keyframes jump {
+                0% {
+                    transform: translateY(0px);
+                }
+                20% {
+                    transform: translateY(5px);
+                }
+                60% {
+                    transform: translateY(-25px);
+                }
+                90% {
+                    transform: translateY(3px);
+                }
+                100% {
+                    transform: translateY(0px);
+                }
+            }
+
+            @media (max-height: 680px) {
+                & {
+                    font-size: 3vh;
+                }
+            }
+        "#)
+    }
+}
@@ -0,0 +1,109 @@
+mod storage;
+mod ui;
+
+use std::env;
+use std::io::{self, stdout, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crossterm::cursor::{Hide, Show};
+use crossterm::event::{read, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ywd_core::{today_puzzle_number, GameSession, GameStatus, GuessError, Key, Wordle};
+
+enum Mode {
+    Daily,
+    Practice,
+}
+
+fn parse_mode() -> Result<Mode, String> {
+    match env::args().nth(1).as_deref() {
+        None | Some("daily") => Ok(Mode::Daily),
+        Some("practice") => Ok(Mode::Practice),
+        Some(arg) => Err(format!("unknown mode `{}`, expected `daily` or `practice`", arg)),
+    }
+}
+
+fn to_key(event: KeyEvent) -> Option<Key> {
+    match event.code {
+        KeyCode::Char(c) if event.modifiers.difference(KeyModifiers::SHIFT).is_empty() => {
+            c.to_ascii_lowercase().to_string().parse().ok()
+        },
+        KeyCode::Enter => Some(Key::Enter),
+        KeyCode::Backspace => Some(Key::Backspace),
+        KeyCode::Left => Some(Key::Left),
+        KeyCode::Right => Some(Key::Right),
+        _ => None,
+    }
+}
+
+fn is_quit(event: &KeyEvent) -> bool {
+    event.code == KeyCode::Esc
+        || (event.code == KeyCode::Char('c') && event.modifiers.contains(KeyModifiers::CONTROL))
+}
+
+fn play(out: &mut impl Write, mode: Mode) -> io::Result<()> {
+    let puzzle = today_puzzle_number();
+    let (title, mut session) = match mode {
+        Mode::Daily => ("YWD - daily", GameSession::of_the_day()),
+        Mode::Practice => {
+            let seed = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| {d.as_nanos() as u64});
+            ("YWD - practice", GameSession::new(Wordle::new_practice(seed)))
+        },
+    };
+    let mut message = String::new();
+    while session.status() == GameStatus::InProgress {
+        ui::draw(out, title, &session, &message)?;
+        if let Event::Key(event) = read()? {
+            if is_quit(&event) {
+                return Ok(());
+            }
+            message = match to_key(event).map(|key| {session.press(key)}) {
+                Some(Err(GuessError::NotEnoughLetters)) => "Not enough letters".to_string(),
+                Some(Err(GuessError::NotInWordList)) => "Not in word list".to_string(),
+                _ => String::new(),
+            };
+        }
+    }
+
+    let (mut stats, last_daily) = storage::load_stats();
+    // Only daily games count, and each daily only once.
+    let saved = match mode {
+        Mode::Daily if last_daily != Some(puzzle) => {
+            stats.record(session.status(), session.guesses().len(), session.hints().len());
+            storage::save_stats(&stats, puzzle)
+        },
+        _ => Ok(()),
+    };
+    let message = match saved {
+        Ok(_) if session.status() == GameStatus::Won => "Splendid!".to_string(),
+        Ok(_) => session.answer().unwrap_or_default().to_uppercase(),
+        Err(e) => format!("Failed to save stats: {}", e),
+    };
+    ui::draw(out, title, &session, &message)?;
+    ui::draw_stats(out, &session, &stats)?;
+    loop {
+        if let Event::Key(_) = read()? {
+            return Ok(());
+        }
+    }
+}
+
+fn main() {
+    let mode = match parse_mode() {
+        Ok(mode) => mode,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        },
+    };
+    let mut out = stdout();
+    let result = enable_raw_mode()
+        .and_then(|_| {execute!(out, EnterAlternateScreen, Hide)})
+        .and_then(|_| {play(&mut out, mode)});
+    execute!(out, Show, LeaveAlternateScreen).ok();
+    disable_raw_mode().ok();
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,34 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use ywd_core::Stats;
+
+fn data_dir() -> Option<PathBuf> {
+    match env::var_os("XDG_DATA_HOME") {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => env::var_os("HOME").map(|home| {PathBuf::from(home).join(".local/share")}),
+    }.map(|dir| {dir.join("ywd")})
+}
+
+fn stats_file() -> Option<PathBuf> {
+    data_dir().map(|dir| {dir.join("stats")})
+}
+
+/// The stats, and the number of the last daily puzzle they counted. Files from before that was kept have
+/// only the stats line.
+pub fn load_stats() -> (Stats, Option<i64>) {
+    let content = stats_file().and_then(|file| {fs::read_to_string(file).ok()}).unwrap_or_default();
+    let mut lines = content.lines();
+    let stats = lines.next().and_then(|line| {line.trim().parse().ok()}).unwrap_or_default();
+    (stats, lines.next().and_then(|line| {line.trim().parse().ok()}))
+}
+
+pub fn save_stats(stats: &Stats, last_daily: i64) -> io::Result<()> {
+    let file = stats_file()
+        .ok_or_else(|| {io::Error::new(io::ErrorKind::NotFound, "no data directory")})?;
+    if let Some(dir) = file.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(file, format!("{}\n{}\n", stats, last_daily))
+}
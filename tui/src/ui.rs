@@ -0,0 +1,84 @@
+use std::io::{self, Write};
+use crossterm::cursor::MoveTo;
+use crossterm::style::{Color, PrintStyledContent, Stylize};
+use crossterm::terminal::{Clear, ClearType};
+use crossterm::queue;
+use ywd_core::{GameSession, GameStatus, LetterHint, Stats, MAX_GUESSES};
+
+const KEYBOARD_ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+fn hint_color(hint: Option<LetterHint>) -> Color {
+    match hint {
+        Some(LetterHint::Correct) => Color::Rgb { r: 0x6a, g: 0xaa, b: 0x64 },
+        Some(LetterHint::Present) => Color::Rgb { r: 0xc9, g: 0xb4, b: 0x58 },
+        Some(LetterHint::Absent) => Color::Rgb { r: 0x78, g: 0x7c, b: 0x7e },
+        None => Color::Rgb { r: 0xd3, g: 0xd6, b: 0xda },
+    }
+}
+
+fn tile(c: char, hint: Option<LetterHint>) -> crossterm::style::StyledContent<String> {
+    let text = format!(" {} ", c.to_ascii_uppercase());
+    match hint {
+        Some(_) => text.with(Color::White).on(hint_color(hint)).bold(),
+        None => text.with(Color::Black).on(Color::White).bold(),
+    }
+}
+
+pub fn draw(out: &mut impl Write, title: &str, session: &GameSession, message: &str) -> io::Result<()> {
+    queue!(out, Clear(ClearType::All), MoveTo(2, 0), PrintStyledContent(title.bold()))?;
+
+    let guesses = session.guesses();
+    for row in 0..MAX_GUESSES {
+        let y = 2 + row as u16 * 2;
+        for col in 0..session.width() {
+            let x = 2 + col as u16 * 4;
+            let content = match guesses.get(row) {
+                Some(hints) => tile(hints[col].0, Some(hints[col].1)),
+                None if row == guesses.len() => {
+                    let current_guess = session.current_guess();
+                    let content = tile(current_guess.get(col).unwrap_or(' '), None);
+                    if col == current_guess.cursor() { content.underlined() } else { content }
+                },
+                None => tile(' ', None),
+            };
+            queue!(out, MoveTo(x, y), PrintStyledContent(content))?;
+        }
+    }
+
+    let key_states = session.key_states();
+    for (i, row) in KEYBOARD_ROWS.iter().enumerate() {
+        let y = 3 + MAX_GUESSES as u16 * 2 + i as u16;
+        queue!(out, MoveTo(2 + i as u16, y))?;
+        for c in row.chars() {
+            let key = format!(" {} ", c.to_ascii_uppercase())
+                .with(Color::Black)
+                .on(hint_color(key_states.get(c)));
+            queue!(out, PrintStyledContent(key), PrintStyledContent(" ".reset()))?;
+        }
+    }
+
+    let y = 7 + MAX_GUESSES as u16 * 2;
+    queue!(out, MoveTo(2, y), PrintStyledContent(message.bold()))?;
+    out.flush()
+}
+
+pub fn draw_stats(out: &mut impl Write, session: &GameSession, stats: &Stats) -> io::Result<()> {
+    let y = 9 + MAX_GUESSES as u16 * 2;
+    let summary = format!(
+        "Played {}  Win % {}  Current streak {}  Max streak {}",
+        stats.played, stats.win_rate(), stats.current_streak, stats.max_streak,
+    );
+    queue!(out, MoveTo(2, y), PrintStyledContent(summary.reset()))?;
+    let max = stats.distribution.iter().copied().max().unwrap_or(0).max(1);
+    for (i, count) in stats.distribution.iter().enumerate() {
+        let width = (*count * 30 / max) as usize + 1;
+        let highlight = session.status() == GameStatus::Won && session.guesses().len() == i + 1;
+        let bar = format!("{:<width$}", count, width = width)
+            .with(Color::White)
+            .on(if highlight { hint_color(Some(LetterHint::Correct)) } else { hint_color(Some(LetterHint::Absent)) });
+        queue!(out, MoveTo(2, y + 2 + i as u16), PrintStyledContent(format!("{} ", i + 1).reset()), PrintStyledContent(bar))?;
+    }
+    let hint = "Press any key to quit";
+    queue!(out, MoveTo(2, y + 3 + MAX_GUESSES as u16), PrintStyledContent(hint.dim()))?;
+    out.flush()
+}